@@ -0,0 +1,10 @@
+-- Servers may opt into storing the raw bytes of attachments on reported
+-- messages, so evidence survives the attachment being deleted from Discord.
+-- When either of these is null, only the attachment URLs are archived.
+
+-- The largest single attachment (in bytes) that will be downloaded
+ALTER TABLE server_configuration ADD COLUMN attachment_max_size BIGINT;
+
+-- The total amount of attachment bytes (across all archived attachments in the
+-- server) that may be stored
+ALTER TABLE server_configuration ADD COLUMN attachment_quota BIGINT;
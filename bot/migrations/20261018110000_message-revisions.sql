@@ -0,0 +1,15 @@
+-- Reported messages may be edited or deleted after they are reported. Every
+-- revision is stored in `message_archive`, so we record when each revision was
+-- archived. This is null for revisions archived before this was tracked.
+ALTER TABLE message_archive ADD COLUMN archived DATETIME;
+
+-- Messages that were deleted after being reported, and when we noticed.
+CREATE TABLE deleted_messages (
+    message_id BIGINT PRIMARY KEY NOT NULL,
+
+    deleted DATETIME NOT NULL
+);
+
+-- Every edit or deletion of a message has to check if the message is reported,
+-- so reports need to be searchable by message.
+CREATE INDEX reports_by_message_id ON reports(message_id);
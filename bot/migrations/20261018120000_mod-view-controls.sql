@@ -0,0 +1,3 @@
+-- Mod views are controlled with buttons by default. Servers may fall back to
+-- the older reaction-based controls instead.
+ALTER TABLE server_configuration ADD COLUMN mod_view_reactions BOOLEAN NOT NULL DEFAULT FALSE;
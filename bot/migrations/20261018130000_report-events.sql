@@ -0,0 +1,28 @@
+-- An audit log of everything that happens to a report, so moderators can tell
+-- who changed what (and what it used to be).
+CREATE TABLE report_events (
+    -- Events are ordered by their ID
+    id INTEGER PRIMARY KEY NOT NULL,
+
+    -- The report this event happened to
+    report_id INTEGER NOT NULL,
+
+    -- The user that caused the event. Null when nobody in particular caused it,
+    -- such as the reported message being deleted.
+    actor_user_id BIGINT,
+
+    -- What kind of event this is (see `ReportEventKind`)
+    kind INTEGER NOT NULL,
+
+    -- The value before and after the event, if the event changed a value.
+    -- Statuses are stored as their integer representation, and handlers as
+    -- their user ID.
+    old_value TEXT,
+    new_value TEXT,
+
+    -- When the event happened
+    created DATETIME NOT NULL
+);
+
+-- The history of a report is always loaded in order.
+CREATE INDEX report_events_by_report_id ON report_events(report_id, id ASC);
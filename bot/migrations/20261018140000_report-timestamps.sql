@@ -0,0 +1,14 @@
+-- When a report was made, last changed, and accepted or denied. These are null
+-- for reports made before they were tracked, and `resolved_at` is null while
+-- the report is still open.
+ALTER TABLE reports ADD COLUMN created_at DATETIME;
+ALTER TABLE reports ADD COLUMN updated_at DATETIME;
+ALTER TABLE reports ADD COLUMN resolved_at DATETIME;
+
+-- Reports made since the audit log was introduced know when they were made.
+UPDATE reports
+SET created_at = (
+    SELECT MIN(created) FROM report_events
+    WHERE report_events.report_id = reports.id
+      AND report_events.kind = 0
+);
@@ -0,0 +1,17 @@
+-- Private notes moderators attach to reports, such as why a report was
+-- accepted or denied. These are only ever shown to moderators.
+CREATE TABLE report_notes (
+    id INTEGER PRIMARY KEY NOT NULL,
+
+    -- The report the note is about
+    report_id INTEGER NOT NULL,
+
+    -- The moderator that wrote the note
+    author_user_id BIGINT NOT NULL,
+
+    content TEXT NOT NULL,
+
+    created DATETIME NOT NULL
+);
+
+CREATE INDEX report_notes_by_report_id ON report_notes(report_id, id ASC);
@@ -0,0 +1,11 @@
+-- Moderators can search the reports of their server in a few ways, which are
+-- always shown newest first.
+CREATE INDEX reports_by_guild_id ON reports(guild_id, id DESC);
+CREATE INDEX reports_by_status ON reports(guild_id, status, id DESC);
+CREATE INDEX reports_by_reported_user_id ON reports(guild_id, reported_user_id, id DESC);
+CREATE INDEX reports_by_accuser_user_id ON reports(guild_id, accuser_user_id, id DESC);
+CREATE INDEX reports_by_channel_id ON reports(guild_id, channel_id, id DESC);
+CREATE INDEX reports_by_created_at ON reports(guild_id, created_at);
+
+-- Searching by handler goes through the mod views.
+CREATE INDEX discord_mod_view_by_handler ON discord_mod_view(handler);
@@ -0,0 +1,8 @@
+-- Reporters whose reports are mostly denied can be held to a higher standard.
+-- The threshold is the percentage of resolved reports that must have been
+-- accepted, and is null when reputation isn't checked.
+ALTER TABLE server_configuration ADD COLUMN reputation_threshold INTEGER;
+
+-- Whether reporters below the threshold must give a reason for their reports.
+-- Otherwise, their reports are only flagged.
+ALTER TABLE server_configuration ADD COLUMN reputation_requires_reason BOOLEAN NOT NULL DEFAULT FALSE;
@@ -0,0 +1,20 @@
+-- Users that may not make reports in a server, such as users that abuse
+-- reporting. This is the inverse of `protected_users`.
+CREATE TABLE report_banned_users (
+    guild_id BIGINT NOT NULL,
+    banned_user_id BIGINT NOT NULL,
+
+    -- The administrator that banned the user
+    banned_by_user_id BIGINT NOT NULL,
+
+    -- Why the user was banned, which is shown to the user
+    reason TEXT,
+
+    -- When the user was banned
+    created DATETIME NOT NULL,
+
+    -- When the ban ends. Bans without an expiry last until they're lifted.
+    expires DATETIME,
+
+    PRIMARY KEY (guild_id, banned_user_id)
+);
@@ -0,0 +1,8 @@
+-- Roles whose members can't be reported, so staff don't have to be protected
+-- one by one.
+CREATE TABLE protected_roles (
+    guild_id BIGINT NOT NULL,
+    protected_role_id BIGINT NOT NULL,
+
+    PRIMARY KEY (guild_id, protected_role_id)
+);
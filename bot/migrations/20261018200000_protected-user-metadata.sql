@@ -0,0 +1,6 @@
+-- Record who protected a user and when, so the protected users can be listed
+-- in order. These are null for users protected before this was tracked.
+ALTER TABLE protected_users ADD COLUMN protected_by_user_id BIGINT;
+ALTER TABLE protected_users ADD COLUMN protected_at DATETIME;
+
+CREATE INDEX protected_users_by_protected_at ON protected_users(guild_id, protected_at DESC);
@@ -0,0 +1,34 @@
+-- Reports are rate limited with token buckets, which allow `count` reports
+-- every `seconds`. A count of zero disables the limit.
+ALTER TABLE server_configuration ADD COLUMN user_rate_limit_count INTEGER NOT NULL DEFAULT 5;
+ALTER TABLE server_configuration ADD COLUMN user_rate_limit_seconds INTEGER NOT NULL DEFAULT 60;
+ALTER TABLE server_configuration ADD COLUMN guild_rate_limit_count INTEGER NOT NULL DEFAULT 30;
+ALTER TABLE server_configuration ADD COLUMN guild_rate_limit_seconds INTEGER NOT NULL DEFAULT 60;
+
+-- Once a user has been reported this many times within the window, further
+-- reports against them are collapsed into a single burst mod view. A threshold
+-- of zero disables bursts.
+ALTER TABLE server_configuration ADD COLUMN burst_threshold INTEGER NOT NULL DEFAULT 5;
+ALTER TABLE server_configuration ADD COLUMN burst_window_seconds INTEGER NOT NULL DEFAULT 600;
+
+-- A burst of reports against the same user. The burst stays open while
+-- reports keep arriving within the window of the last one.
+CREATE TABLE report_bursts (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id BIGINT NOT NULL,
+    reported_user_id BIGINT NOT NULL,
+    -- the message the burst is shown in, null until it has been sent
+    channel_id BIGINT,
+    message_id BIGINT,
+    created_at DATETIME NOT NULL,
+    updated_at DATETIME NOT NULL
+);
+
+CREATE INDEX report_bursts_by_reported_user ON report_bursts(guild_id, reported_user_id, updated_at);
+
+CREATE TABLE report_burst_reports (
+    report_id INTEGER PRIMARY KEY NOT NULL REFERENCES reports(id),
+    burst_id INTEGER NOT NULL REFERENCES report_bursts(id)
+);
+
+CREATE INDEX report_burst_reports_by_burst ON report_burst_reports(burst_id);
@@ -0,0 +1,26 @@
+-- Reports against the same message (or, optionally, the same user within a
+-- window) are grouped into a case. The case is shown on the mod view of the
+-- report that started it, and resolving that report resolves the whole case.
+CREATE TABLE report_cases (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id BIGINT NOT NULL,
+    reported_user_id BIGINT NOT NULL,
+    -- null when the case groups reports against the user
+    message_id BIGINT,
+    lead_report_id INTEGER NOT NULL REFERENCES reports(id),
+    created_at DATETIME NOT NULL
+);
+
+CREATE INDEX report_cases_by_message ON report_cases(guild_id, message_id);
+CREATE INDEX report_cases_by_reported_user ON report_cases(guild_id, reported_user_id, created_at);
+
+CREATE TABLE report_case_reports (
+    report_id INTEGER PRIMARY KEY NOT NULL REFERENCES reports(id),
+    case_id INTEGER NOT NULL REFERENCES report_cases(id)
+);
+
+CREATE INDEX report_case_reports_by_case ON report_case_reports(case_id);
+
+-- How long reports against the same user are grouped into a case for, or null
+-- if they're only grouped by message.
+ALTER TABLE server_configuration ADD COLUMN case_window_seconds INTEGER;
@@ -0,0 +1,18 @@
+-- How many members must flag a message before its reports are sent to the
+-- moderators. Reports against users (without a message) are always sent.
+ALTER TABLE server_configuration ADD COLUMN report_threshold INTEGER NOT NULL DEFAULT 1;
+
+-- How many members must flag a message before it's deleted, or null if
+-- messages are never deleted automatically.
+ALTER TABLE server_configuration ADD COLUMN auto_hide_threshold INTEGER;
+
+-- Reports held back until their message reaches the report threshold.
+CREATE TABLE pending_reports (
+    report_id INTEGER PRIMARY KEY NOT NULL REFERENCES reports(id),
+    guild_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    -- the archived copy of the message to preview once the report is sent
+    preview_archive_id BIGINT
+);
+
+CREATE INDEX pending_reports_by_message ON pending_reports(guild_id, message_id);
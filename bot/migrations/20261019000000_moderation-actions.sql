@@ -0,0 +1,18 @@
+-- Which moderation actions moderators can take when accepting a report, as a
+-- bit set of `ModerationActionKind`s. Only deleting the message is allowed by
+-- default.
+ALTER TABLE server_configuration ADD COLUMN moderation_actions INTEGER NOT NULL DEFAULT 1;
+
+-- The moderation actions taken against reported users.
+CREATE TABLE report_actions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    report_id INTEGER NOT NULL REFERENCES reports(id),
+    moderator_user_id BIGINT NOT NULL,
+    -- the integer representation of a `ModerationActionKind`
+    kind INTEGER NOT NULL,
+    -- how long the member was timed out for
+    duration_seconds INTEGER,
+    created DATETIME NOT NULL
+);
+
+CREATE INDEX report_actions_by_report ON report_actions(report_id);
@@ -0,0 +1,19 @@
+-- Servers may opt into warning reported users in their DMs when a report
+-- against them is accepted. The reporter is never revealed.
+ALTER TABLE server_configuration ADD COLUMN warn_reported_users BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- The template for the warning, with `{server}`, `{rule}` and `{note}`
+-- placeholders. When null, a built-in template is used.
+ALTER TABLE server_configuration ADD COLUMN warning_template TEXT;
+
+-- What reported users are told when a report against them is accepted.
+CREATE TABLE report_warnings (
+    report_id INTEGER PRIMARY KEY NOT NULL REFERENCES reports(id),
+    moderator_user_id BIGINT NOT NULL,
+    -- the rule the reported user broke
+    rule TEXT,
+    -- a note from the moderators, which the reported user gets to see
+    note TEXT,
+    -- when the warning was sent, or null if it hasn't been sent yet
+    sent DATETIME
+);
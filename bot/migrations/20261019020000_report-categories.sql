@@ -0,0 +1,19 @@
+-- Servers may define categories of reports (like spam or harassment), each
+-- with its own report emoji and slash command choice. Reports in a category
+-- are sent to its channel, pinging its role.
+CREATE TABLE report_categories (
+    id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+    guild_id BIGINT NOT NULL,
+    name TEXT NOT NULL,
+    -- the emoji members react with to make a report in this category, if any
+    emoji_builtin TEXT,
+    emoji_custom BIGINT,
+    -- where the category's reports are sent, or null for the reports channel
+    channel_id BIGINT,
+    -- the role pinged when a report in this category is sent
+    ping_role_id BIGINT,
+    UNIQUE (guild_id, name)
+);
+
+-- The category a report was made in, or null for uncategorized reports.
+ALTER TABLE reports ADD COLUMN category_id INTEGER REFERENCES report_categories(id);
@@ -0,0 +1,21 @@
+-- The role pinged whenever a report is sent to the moderators.
+ALTER TABLE server_configuration ADD COLUMN mod_role_id BIGINT;
+
+-- The role pinged when a report has been left alone for too long.
+ALTER TABLE server_configuration ADD COLUMN admin_role_id BIGINT;
+
+-- How long a report may stay unhandled, or be under review, before the
+-- moderators are reminded about it. Null disables the reminders.
+ALTER TABLE server_configuration ADD COLUMN unhandled_sla_seconds INTEGER;
+ALTER TABLE server_configuration ADD COLUMN reviewing_sla_seconds INTEGER;
+
+-- How many times moderators have been reminded about a report since it last
+-- changed status.
+CREATE TABLE report_escalations (
+    report_id INTEGER PRIMARY KEY NOT NULL REFERENCES reports(id),
+    -- the integer representation of the `ReportStatus` the report was
+    -- escalated in. Escalations in a previous status are ignored.
+    status INTEGER NOT NULL,
+    level INTEGER NOT NULL,
+    alerted_at DATETIME NOT NULL
+);
@@ -0,0 +1,7 @@
+-- Servers may opt into discussing each report in its own thread, started from
+-- its mod view. Reports sent to a forum channel always get their own post.
+ALTER TABLE server_configuration ADD COLUMN mod_view_threads BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- The thread the report is discussed in. For forum posts, this is also the
+-- channel the mod view is in.
+ALTER TABLE discord_mod_view ADD COLUMN thread_id INTEGER;
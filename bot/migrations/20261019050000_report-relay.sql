@@ -0,0 +1,29 @@
+-- Moderators may ask reporters for more information through the bot, which
+-- relays messages between the report's thread and the reporter's DMs. Unless
+-- a server turns this off, moderators are shown to reporters anonymously.
+ALTER TABLE server_configuration ADD COLUMN relay_anonymous BOOLEAN NOT NULL DEFAULT TRUE;
+
+-- Every message relayed between moderators and a reporter.
+CREATE TABLE report_relay_messages (
+    id INTEGER PRIMARY KEY NOT NULL,
+
+    -- The report the conversation is about
+    report_id INTEGER NOT NULL,
+
+    -- The moderator or reporter that wrote the message
+    author_user_id BIGINT NOT NULL,
+
+    -- Whether the message was sent to the reporter, rather than from them
+    to_reporter BOOLEAN NOT NULL,
+
+    content TEXT NOT NULL,
+
+    -- Where the bot relayed the message to, so replies to it can be relayed
+    -- back
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+
+    created DATETIME NOT NULL
+);
+
+CREATE INDEX report_relay_messages_by_message_id ON report_relay_messages(message_id);
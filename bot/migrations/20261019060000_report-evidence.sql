@@ -0,0 +1,21 @@
+-- Reports filed through a ticket in DMs may come with evidence: messages in
+-- the server the reporter linked to, and screenshots they sent to the bot.
+-- Both are archived like reported messages, so they survive being deleted.
+CREATE TABLE report_evidence (
+    report_id INTEGER NOT NULL,
+
+    -- The order the reporter gave the evidence in. Zero-based.
+    idx INTEGER NOT NULL,
+
+    -- Where the message is. For screenshots, this is the reporter's DMs.
+    channel_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+
+    -- The archived copy of the message, which maps to `message_archive.id`
+    archive_id INTEGER NOT NULL,
+
+    -- Whether the message was linked to, rather than sent to the bot
+    is_linked BOOLEAN NOT NULL,
+
+    PRIMARY KEY (report_id, idx)
+);
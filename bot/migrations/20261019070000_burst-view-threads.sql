@@ -0,0 +1,3 @@
+-- The thread a burst is discussed in, like the thread of a mod view. For forum
+-- posts, this is also the channel the burst view is in.
+ALTER TABLE report_bursts ADD COLUMN thread_id INTEGER;
@@ -0,0 +1,8 @@
+-- Messages deleted automatically for reaching the auto-hide threshold, so that
+-- they're only deleted once.
+CREATE TABLE hidden_messages (
+    guild_id BIGINT NOT NULL,
+    message_id BIGINT NOT NULL,
+    hidden_at DATETIME NOT NULL,
+    PRIMARY KEY (guild_id, message_id)
+);
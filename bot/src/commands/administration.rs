@@ -1,12 +1,13 @@
 use std::{fmt::Display, str::ParseBoolError, time::Duration};
 
 use serenity::{
+    builder::CreateEmbed,
     client::Context,
     collector::ReactionAction,
     framework::standard::{macros::*, ArgError, Args, CommandResult},
     model::{
         channel::{Channel, Message, ReactionType},
-        id::{GuildId, UserId},
+        id::{GuildId, RoleId, UserId},
         prelude::User,
     },
 };
@@ -14,10 +15,19 @@ use serenity::{
 use thiserror::Error;
 
 use crate::{
-    database::Database,
+    database::{
+        models::{ModerationActionKind, RateLimit, ReportCategoryModel, MIN_RESOLVED_REPORTS},
+        Database, MakeReportEffect,
+    },
+    listeners::{application_commands, relay::RelayError},
     parsing::{self, FailedUserParse, ParsedUser},
-    serenity_utils,
+    serenity_utils::{
+        self,
+        menu::{menu, MenuOptions},
+    },
+    services::{self, DEFAULT_WARNING_TEMPLATE, WARNING_TEMPLATE_MAX_LENGTH},
     state::State,
+    view::{self, UpdateViewError},
 };
 use serenity::futures::StreamExt;
 use serenity::prelude::Mentionable;
@@ -44,6 +54,8 @@ pub enum SetupCommandError {
     InvalidConfirmation(crate::serenity_utils::Error),
     #[error("Configuration rejected")]
     RejectedConfiguration,
+    #[error("Expected a report threshold of at least 1, optionally followed by a threshold at least as high for deleting the message")]
+    InvalidReportThreshold,
 }
 
 impl From<crate::serenity_utils::Error> for SetupCommandError {
@@ -69,6 +81,12 @@ pub async fn setup(ctx: &Context, msg: &Message) -> CommandResult {
     let reports_channel = configure_reports_channel(msg, ctx).await?;
     let report_emote = configure_report_emote(msg, ctx).await?;
     let prefix = configure_prefix(msg, ctx).await?;
+    let (report_threshold, auto_hide_threshold) = configure_report_threshold(msg, ctx).await?;
+
+    let threshold_description = match auto_hide_threshold {
+        Some(auto_hide) => format!("{} member(s), hidden at {}", report_threshold, auto_hide),
+        None => format!("{} member(s)", report_threshold),
+    };
 
     let confirmation = msg
         .channel_id
@@ -78,6 +96,7 @@ pub async fn setup(ctx: &Context, msg: &Message) -> CommandResult {
                     .field("Reports Channel", reports_channel.mention(), true)
                     .field("Report Emoji", format!("{}", report_emote), true)
                     .field("Narc Prefix", &prefix, true)
+                    .field("Report Threshold", &threshold_description, true)
             })
         })
         .await?;
@@ -93,8 +112,15 @@ pub async fn setup(ctx: &Context, msg: &Message) -> CommandResult {
     let read = ctx.data.read().await;
     let db = read.get::<Database>().unwrap();
 
-    db.save_server_configuration(guild_id, report_emote, reports_channel.id(), Some(&prefix))
-        .await?;
+    db.save_server_configuration(
+        guild_id,
+        report_emote,
+        reports_channel.id(),
+        Some(&prefix),
+        report_threshold,
+        auto_hide_threshold,
+    )
+    .await?;
 
     std::mem::drop(in_setup);
 
@@ -117,7 +143,7 @@ async fn configure_reports_channel(
     msg.channel_id
         .send_message(&ctx, |m| {
             m.embed(|e| {
-                e.title("Configure Narc (1/3)").field(
+                e.title("Configure Narc (1/4)").field(
                     "Reports Channel",
                     "Please type the channel that reports will be sent to",
                     false,
@@ -156,7 +182,7 @@ async fn configure_report_emote(
         .channel_id
         .send_message(&ctx, |m| {
             m.embed(|e| {
-                e.title("Configure Narc (2/3)").field(
+                e.title("Configure Narc (2/4)").field(
                     "Report Emote",
                     "Please react or type the emote to use for reports. Suggested: 🚩",
                     false,
@@ -184,17 +210,7 @@ async fn configure_report_emote(
                 .await
                 .ok_or(SetupCommandError::Timeout)?;
 
-        if let Some(twemoji) = (text_response.chars())
-            .take(1)
-            .find(|&c| unic::emoji::char::is_emoji(c))
-        {
-            return Ok(ReactionType::Unicode(twemoji.to_string()));
-        }
-
-        match serenity::utils::parse_emoji(text_response) {
-            Some(emoji) => Ok(dbg!(emoji.into())),
-            None => Err(SetupCommandError::UnparseableEmoji),
-        }
+        parsing::emoji(&text_response).ok_or(SetupCommandError::UnparseableEmoji)
     }
 
     async fn accept_reaction(
@@ -223,7 +239,7 @@ async fn configure_prefix(msg: &Message, ctx: &Context) -> Result<String, SetupC
         .channel_id
         .send_message(&ctx, |m| {
             m.embed(|e| {
-                e.title("Configure Narc (3/3)").field(
+                e.title("Configure Narc (3/4)").field(
                     "Prefix",
                     "What prefix should Narc respond to? Suggested: **`n!`**",
                     false,
@@ -244,6 +260,54 @@ async fn configure_prefix(msg: &Message, ctx: &Context) -> Result<String, SetupC
     Ok(prompt)
 }
 
+/// Asks how many members must flag a message before it's sent to the
+/// moderators, and optionally how many before it's deleted.
+async fn configure_report_threshold(
+    msg: &Message,
+    ctx: &Context,
+) -> Result<(u32, Option<u32>), SetupCommandError> {
+    let threshold_prompt = msg
+        .channel_id
+        .send_message(&ctx, |m| {
+            m.embed(|e| {
+                e.title("Configure Narc (4/4)").field(
+                    "Report Threshold",
+                    "How many members must flag a message before it's sent to the moderators? Reports on messages that never reach it aren't sent. Optionally, follow it with how many flags should delete the message (like `3 10`). Suggested: **`1`**",
+                    false,
+                )
+            })
+        })
+        .await?;
+
+    let prompt = crate::serenity_utils::prompt::message_prompt_content(
+        ctx,
+        &threshold_prompt,
+        &msg.author,
+        30.0,
+    )
+    .await
+    .ok_or(SetupCommandError::Timeout)?;
+
+    let mut thresholds = prompt.split_whitespace().map(|t| t.parse::<u32>());
+
+    let report_threshold = match thresholds.next() {
+        Some(Ok(threshold)) if threshold > 0 => threshold,
+        _ => return Err(SetupCommandError::InvalidReportThreshold),
+    };
+
+    let auto_hide_threshold = match thresholds.next() {
+        Some(Ok(threshold)) if threshold >= report_threshold => Some(threshold),
+        Some(_) => return Err(SetupCommandError::InvalidReportThreshold),
+        None => None,
+    };
+
+    if thresholds.next().is_some() {
+        return Err(SetupCommandError::InvalidReportThreshold);
+    }
+
+    Ok((report_threshold, auto_hide_threshold))
+}
+
 struct InSetup<'ctx> {
     ctx: &'ctx Context,
     user_id: &'ctx UserId,
@@ -291,26 +355,24 @@ pub enum ProtectError {
     UserParseError(#[from] FailedUserParse),
     #[error("Error parsing new status")]
     StatusParseError(#[from] ParseBoolError),
+    #[error("Unable to show the protected users: {0}")]
+    MenuError(#[from] crate::serenity_utils::Error),
+    #[error("Unknown sort `{0}`, expected `recent`, `oldest` or `protector`")]
+    UnknownSort(String),
+    #[error("Expected a user, a role or a `--sort=` flag")]
+    InvalidArguments,
 }
 
 #[command]
 #[required_permissions(ADMINISTRATOR)]
-#[description("Set (or show) a user's protected status.")]
+#[usage("<user|role> [true|false]")]
+#[description("Set (or show) a user's or role's protected status. Members with a protected role can't be reported.")]
 pub async fn protect(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild = msg.guild(&ctx).ok_or(ProtectError::NoUserSpecified)?;
 
-    let user = match args.single_quoted::<String>() {
-        Ok(name) => {
-            let user = parsing::user(&name, &ctx, &guild).await?;
-            let user = ctx
-                .http
-                .get_user(user.user_id().0)
-                .await
-                .map_err(ProtectError::UserLoadError)?;
-            user
-        }
-        Err(ArgError::Eos) => return Err(ProtectError::NoUserSpecified.into()),
-        Err(_) => unreachable!("infallible"),
+    let name = match args.single_quoted::<String>() {
+        Ok(name) => name,
+        Err(_) => return Err(ProtectError::NoUserSpecified.into()),
     };
 
     let new_status = match args.single_quoted::<bool>() {
@@ -320,6 +382,24 @@ pub async fn protect(ctx: &Context, msg: &Message, mut args: Args) -> CommandRes
         Err(_) => unreachable!("non exhaustive arg error"),
     };
 
+    if let Some(role) = parsing::role(&name, &guild) {
+        match new_status {
+            Some(new_status) => {
+                update_protected_role_status(ctx, msg, &guild.id, role, new_status).await?
+            }
+            None => show_protected_role_status(ctx, msg, &guild.id, role).await?,
+        };
+
+        return Ok(());
+    }
+
+    let user = parsing::user(&name, &ctx, &guild).await?;
+    let user = ctx
+        .http
+        .get_user(user.user_id().0)
+        .await
+        .map_err(ProtectError::UserLoadError)?;
+
     match new_status {
         Some(new_status) => {
             update_protected_user_status(ctx, msg, &guild.id, user, new_status).await?
@@ -330,31 +410,78 @@ pub async fn protect(ctx: &Context, msg: &Message, mut args: Args) -> CommandRes
     Ok(())
 }
 
+/// How many users are shown on each page of `n!protected`.
+const PROTECTED_USERS_PER_PAGE: usize = 10;
+
+/// The order `n!protected` lists protected users in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ProtectedSort {
+    /// Most recently protected first.
+    Recent,
+    /// Longest protected first.
+    Oldest,
+    /// Grouped by who protected them, most recently protected first.
+    Protector,
+}
+
+/// The flag that sorts the list of protected users. It's a flag so that it
+/// can't be mistaken for the name of a user or role.
+const PROTECTED_SORT_FLAG: &str = "--sort=";
+
+impl ProtectedSort {
+    /// Parses the `--sort=` flag, returning `None` if the argument isn't one.
+    fn parse(arg: &str) -> Option<Result<Self, ProtectError>> {
+        let sort = arg.strip_prefix(PROTECTED_SORT_FLAG)?;
+
+        Some(match sort.to_lowercase().as_str() {
+            "recent" | "newest" => Ok(Self::Recent),
+            "oldest" => Ok(Self::Oldest),
+            "protector" => Ok(Self::Protector),
+            _ => Err(ProtectError::UnknownSort(sort.to_owned())),
+        })
+    }
+}
+
 #[command]
 #[required_permissions(ADMINISTRATOR)]
-#[description("Show a user's protected status.")]
+#[usage("[user|role|--sort=recent|--sort=oldest|--sort=protector]")]
+#[example("--sort=oldest")]
+#[description("Show a user's or role's protected status, or every protected user and role. The list can be sorted by when users were protected (`--sort=recent` or `--sort=oldest`) or by who protected them (`--sort=protector`).")]
 pub async fn protected(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
     let guild = msg.guild(&ctx).ok_or(ProtectError::NoGuild)?;
 
-    let user = match args.single_quoted::<String>() {
-        Ok(name) => {
-            let user = parsing::user(&name, &ctx, &guild).await?;
-            let user = ctx
-                .http
-                .get_user(user.user_id().0)
-                .await
-                .map_err(ProtectError::UserLoadError)?;
-            Some(user)
-        }
+    let name = match args.single_quoted::<String>() {
+        Ok(name) => Some(name),
         Err(ArgError::Eos) => None,
-        Err(_) => unreachable!("infallible"),
+        Err(_) => return Err(ProtectError::InvalidArguments.into()),
     };
 
-    match user {
-        Some(user) => show_protected_user_status(ctx, msg, &guild.id, user).await?,
-        None => show_protected_users(ctx, msg, &guild.id).await?,
+    let sort = match name.as_deref() {
+        Some(name) => ProtectedSort::parse(name).transpose()?,
+        None => Some(ProtectedSort::Recent),
     };
 
+    if let Some(sort) = sort {
+        show_protected_users(ctx, msg, &guild.id, sort).await?;
+        return Ok(());
+    }
+
+    if let Some(role) = name.as_deref().and_then(|n| parsing::role(n, &guild)) {
+        show_protected_role_status(ctx, msg, &guild.id, role).await?;
+        return Ok(());
+    }
+
+    // the sort was `None`, so a name must've been given
+    let name = name.unwrap();
+    let user = parsing::user(&name, &ctx, &guild).await?;
+    let user = ctx
+        .http
+        .get_user(user.user_id().0)
+        .await
+        .map_err(ProtectError::UserLoadError)?;
+
+    show_protected_user_status(ctx, msg, &guild.id, user).await?;
+
     Ok(())
 }
 
@@ -362,53 +489,96 @@ async fn show_protected_users(
     ctx: &Context,
     msg: &Message,
     guild: &GuildId,
+    sort: ProtectedSort,
 ) -> Result<(), ProtectError> {
-    use std::fmt::Write;
-
     let data = ctx.data.read().await;
     let db = data.get::<Database>().unwrap();
 
-    let protected_users = db.load_protected_users(guild).await?;
-
-    // roughly how many bytes the string needs to be
-    const MAX_LENGTH: usize = 2048;
-    let mut user_list = String::with_capacity(MAX_LENGTH + 100);
+    // loaded most recently protected first
+    let mut protected_users = db.load_protected_users(guild).await?;
+    let protected_roles = db.load_protected_roles(guild).await?;
+
+    // the menu waits for button presses, so don't hold onto the database
+    drop(data);
+
+    match sort {
+        ProtectedSort::Recent => {}
+        ProtectedSort::Oldest => {
+            // keep users without a timestamp at the end, they were protected
+            // before it was recorded but their order is unknown
+            let unknown = (protected_users.iter())
+                .position(|u| u.protected_at.is_none())
+                .unwrap_or(protected_users.len());
+            protected_users[..unknown].reverse();
+        }
+        ProtectedSort::Protector => {
+            // group by protector, ordered by who most recently protected
+            // someone. the sort is stable, so each protector's users stay most
+            // recent first, and users with no recorded protector come last
+            let mut protectors = Vec::new();
+            for user in &protected_users {
+                if let Some(by) = user.protected_by {
+                    if !protectors.contains(&by) {
+                        protectors.push(by);
+                    }
+                }
+            }
 
-    let mut iter = protected_users.into_iter();
+            protected_users.sort_by_key(|u| {
+                (u.protected_by)
+                    .and_then(|by| protectors.iter().position(|p| *p == by))
+                    .unwrap_or(protectors.len())
+            });
+        }
+    };
 
-    if let Some(user) = iter.next() {
-        write!(user_list, "{}", user.mention()).unwrap();
-    }
+    let role_list = match protected_roles.is_empty() {
+        true => "*No roles are protected.*".to_owned(),
+        false => (protected_roles.iter())
+            .map(|r| r.mention().to_string())
+            .collect::<Vec<_>>()
+            .join(", "),
+    };
 
-    let mut last_len = user_list.len();
-    for user in iter {
-        write!(user_list, ", {}", user.mention()).unwrap();
+    let sort_name = match sort {
+        ProtectedSort::Recent => "most recently protected first",
+        ProtectedSort::Oldest => "longest protected first",
+        ProtectedSort::Protector => "by who protected them",
+    };
 
-        const ETC: &str = ", ...";
-        if user_list.len() > MAX_LENGTH + ETC.len() {
-            // TODO: there's gotta be a safe way to shrink the string
-            user_list = user_list[0..last_len].to_string();
+    let mut pages = (protected_users.chunks(PROTECTED_USERS_PER_PAGE))
+        .map(|chunk| {
+            let lines = (chunk.iter())
+                .map(|user| match (user.protected_by, user.protected_at) {
+                    (Some(by), Some(at)) => format!(
+                        "{} by {} <t:{}:R>",
+                        user.user_id.mention(),
+                        by.mention(),
+                        at.timestamp()
+                    ),
+                    _ => format!("{} *(unknown)*", user.user_id.mention()),
+                })
+                .collect::<Vec<_>>();
 
-            user_list.push_str(ETC);
-            break;
-        }
+            lines.join("\n")
+        })
+        .collect::<Vec<_>>();
 
-        last_len = user_list.len();
+    if pages.is_empty() {
+        pages.push("*No users are protected.*".to_owned());
     }
 
-    msg.channel_id
-        .send_message(ctx, |m| {
-            m.embed(|e| {
-                e.title("Protected Users")
-                    .field("Users", user_list, false)
-                    .field(
-                        "Note",
-                        "At this time, there is no paging mechanism for showing multiple users.",
-                        true,
-                    )
-            })
+    let pages = (pages.into_iter())
+        .map(|user_list| {
+            let mut page = CreateEmbed::default();
+            page.title("Protected Users")
+                .field(format!("Users ({})", sort_name), user_list, false)
+                .field("Roles", &role_list, false);
+            page
         })
-        .await?;
+        .collect::<Vec<_>>();
+
+    menu(ctx, msg, &msg.author, &pages, MenuOptions::default()).await?;
 
     Ok(())
 }
@@ -468,7 +638,7 @@ async fn update_protected_user_status(
     let db = data.get::<Database>().unwrap();
 
     match new_status {
-        true => db.protect_user(*guild, user.id).await?,
+        true => db.protect_user(*guild, user.id, msg.author.id).await?,
         false => db.unprotect_user(*guild, user.id).await?,
     };
 
@@ -497,3 +667,1311 @@ async fn update_protected_user_status(
 
     Ok(())
 }
+
+async fn show_protected_role_status(
+    ctx: &Context,
+    msg: &Message,
+    guild: &GuildId,
+    role: RoleId,
+) -> Result<(), ProtectError> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let status = db.load_protected_roles(guild).await?.contains(&role);
+
+    let description = match status {
+        true => format!("**YES** - members of {} **are** protected.", role.mention()),
+        false => format!(
+            "**NO** - members of {} **are not** protected.",
+            role.mention()
+        ),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Role Protection Status")
+                    .field("Protected", description, false)
+                    .field(
+                        "Update",
+                        "To change this role's protection status, use `n!protect @role true` or `n!protect @role false`",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn update_protected_role_status(
+    ctx: &Context,
+    msg: &Message,
+    guild: &GuildId,
+    role: RoleId,
+    new_status: bool,
+) -> Result<(), ProtectError> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    match new_status {
+        true => db.protect_role(*guild, role).await?,
+        false => db.unprotect_role(*guild, role).await?,
+    };
+
+    let description = match new_status {
+        true => format!("Members of {} **are** protected.", role.mention()),
+        false => format!("Members of {} **are not** protected.", role.mention()),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Updated Role Protection Status")
+                    .field("Protected", description, false)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ArchivalError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error(
+        "Expected a maximum attachment size (in KB, up to 512000) and a quota (in MB, up to 102400), or `off`"
+    )]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+/// The largest attachment Discord allows, in KB.
+const MAX_ARCHIVAL_SIZE: u64 = 500 * 1024;
+
+/// The most storage a server may set aside for attachments, in MB.
+const MAX_ARCHIVAL_QUOTA: u64 = 100 * 1024;
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[description(
+    "Set (or show) how attachments on reported messages are archived. Use `n!archival <max size in KB> <quota in MB>` to store attachments, or `n!archival off` to only store their links."
+)]
+pub async fn archival(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ArchivalError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(ArchivalError::UnconfiguredServer)?;
+
+    if args.is_empty() {
+        show_archival(
+            ctx,
+            msg,
+            config.attachment_max_size,
+            config.attachment_quota,
+        )
+        .await?;
+        return Ok(());
+    }
+
+    let (max_size, quota) = match args.single_quoted::<String>() {
+        Ok(off) if off.eq_ignore_ascii_case("off") => (None, None),
+        Ok(max_size) => {
+            let max_size = max_size
+                .parse::<u64>()
+                .map_err(|_| ArchivalError::InvalidArguments)?;
+            let quota = args
+                .single_quoted::<u64>()
+                .map_err(|_| ArchivalError::InvalidArguments)?;
+
+            let max_size = Some(max_size)
+                .filter(|s| *s <= MAX_ARCHIVAL_SIZE)
+                .and_then(|s| s.checked_mul(1024))
+                .ok_or(ArchivalError::InvalidArguments)?;
+            let quota = Some(quota)
+                .filter(|q| *q <= MAX_ARCHIVAL_QUOTA)
+                .and_then(|q| q.checked_mul(1024 * 1024))
+                .ok_or(ArchivalError::InvalidArguments)?;
+
+            (Some(max_size), Some(quota))
+        }
+        Err(_) => return Err(ArchivalError::InvalidArguments.into()),
+    };
+
+    db.save_attachment_archival(guild_id, max_size, quota)
+        .await?;
+
+    show_archival(ctx, msg, max_size, quota).await?;
+
+    Ok(())
+}
+
+async fn show_archival(
+    ctx: &Context,
+    msg: &Message,
+    max_size: Option<u64>,
+    quota: Option<u64>,
+) -> Result<(), ArchivalError> {
+    let description = match (max_size, quota) {
+        (Some(max_size), Some(quota)) => format!(
+            "Attachments up to **{} KB** are stored, up to a total of **{} MB**.",
+            max_size / 1024,
+            quota / 1024 / 1024
+        ),
+        _ => "Only links to attachments are stored.".to_string(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Attachment Archival")
+                    .field("Status", description, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!archival <max size in KB> <quota in MB>` or `n!archival off`",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ControlsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected either `buttons` or `reactions`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+    #[error("An error occurred while updating a report: {0}")]
+    ViewError(#[from] UpdateViewError),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[buttons|reactions]")]
+#[description(
+    "Set (or show) whether moderators handle reports with buttons or reactions on the report."
+)]
+pub async fn controls(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ControlsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(ControlsError::UnconfiguredServer)?;
+
+    let reactions = match args.single_quoted::<String>() {
+        Ok(kind) if kind.eq_ignore_ascii_case("buttons") => false,
+        Ok(kind) if kind.eq_ignore_ascii_case("reactions") => true,
+        Ok(_) => return Err(ControlsError::InvalidArguments.into()),
+        Err(ArgError::Eos) => config.mod_view_reactions,
+        Err(_) => return Err(ControlsError::InvalidArguments.into()),
+    };
+
+    if reactions != config.mod_view_reactions {
+        db.save_mod_view_controls(guild_id, reactions).await?;
+
+        // swap the controls on reports that are still being handled
+        for report_id in db.load_open_reports_by_guild(&guild_id).await? {
+            view::update_report_view(ctx, db, MakeReportEffect::Updated(report_id)).await?;
+        }
+    }
+
+    let description = match reactions {
+        true => "Reports are handled by reacting with 🔄 🛄 ❌ ✅.",
+        false => "Reports are handled with buttons.",
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Report Controls")
+                    .field("Controls", description, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!controls buttons` or `n!controls reactions`",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ThreadsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected either `on` or `off`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+    #[error("An error occurred while updating a report: {0}")]
+    ViewError(#[from] UpdateViewError),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[on|off]")]
+#[description(
+    "Set (or show) whether each report gets its own thread to be discussed in, which is archived once the report is accepted or denied. Reports sent to a forum channel always get their own post. Messages in a report's thread or post are sent on to the reporter, except commands."
+)]
+pub async fn threads(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ThreadsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(ThreadsError::UnconfiguredServer)?;
+
+    let threads = match args.single_quoted::<String>() {
+        Ok(toggle) if toggle.eq_ignore_ascii_case("on") => true,
+        Ok(toggle) if toggle.eq_ignore_ascii_case("off") => false,
+        Ok(_) => return Err(ThreadsError::InvalidArguments.into()),
+        Err(ArgError::Eos) => config.mod_view_threads,
+        Err(_) => return Err(ThreadsError::InvalidArguments.into()),
+    };
+
+    if threads != config.mod_view_threads {
+        db.save_mod_view_threads(guild_id, threads).await?;
+
+        // start threads on reports that are still being handled
+        if threads {
+            for report_id in db.load_open_reports_by_guild(&guild_id).await? {
+                view::update_report_view(ctx, db, MakeReportEffect::Updated(report_id)).await?;
+            }
+        }
+    }
+
+    let description = match threads {
+        true => "Each report is discussed in its own thread, and messages there are sent on to the reporter.",
+        false => "Reports are discussed in the reports channel.",
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Report Threads")
+                    .field("Threads", description, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!threads on` or `n!threads off`",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[anonymous|named]")]
+#[description(
+    "Set (or show) whether reporters see which moderator messaged them (through `n!reply` or the report's thread), or only that it came from the server's moderators."
+)]
+pub async fn relay(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(RelayError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(RelayError::UnconfiguredServer)?;
+
+    let anonymous = match args.single_quoted::<String>() {
+        Ok(mode) if mode.eq_ignore_ascii_case("anonymous") => true,
+        Ok(mode) if mode.eq_ignore_ascii_case("named") => false,
+        Ok(_) => return Err(RelayError::InvalidArguments.into()),
+        Err(ArgError::Eos) => config.relay_anonymous,
+        Err(_) => return Err(RelayError::InvalidArguments.into()),
+    };
+
+    if anonymous != config.relay_anonymous {
+        db.save_relay_anonymous(guild_id, anonymous).await?;
+    }
+
+    let description = match anonymous {
+        true => "Reporters only see that messages came from the server's moderators.",
+        false => "Reporters see which moderator messaged them.",
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Reporter Messages")
+                    .field("Moderators", description, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!relay anonymous` or `n!relay named`",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ReputationError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error(
+        "Expected a percentage from 0 to 100 (optionally followed by `flag` or `reason`), or `off`"
+    )]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[off | <percent> [flag|reason]]")]
+#[description(
+    "Set (or show) the share of a reporter's reports that must have been accepted. Reports from reporters below it are flagged, or need a reason with `reason`."
+)]
+pub async fn reputation(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ReputationError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(ReputationError::UnconfiguredServer)?;
+
+    let (threshold, requires_reason) = match args.single_quoted::<String>() {
+        Ok(off) if off.eq_ignore_ascii_case("off") => (None, false),
+        Ok(threshold) => {
+            let threshold = (threshold.trim_end_matches('%').parse::<u8>())
+                .ok()
+                .filter(|t| *t <= 100)
+                .ok_or(ReputationError::InvalidArguments)?;
+
+            let requires_reason = match args.single_quoted::<String>() {
+                Ok(mode) if mode.eq_ignore_ascii_case("flag") => false,
+                Ok(mode) if mode.eq_ignore_ascii_case("reason") => true,
+                Ok(_) => return Err(ReputationError::InvalidArguments.into()),
+                Err(ArgError::Eos) => false,
+                Err(_) => return Err(ReputationError::InvalidArguments.into()),
+            };
+
+            (Some(threshold), requires_reason)
+        }
+        Err(ArgError::Eos) => (
+            config.reputation_threshold,
+            config.reputation_requires_reason,
+        ),
+        Err(_) => return Err(ReputationError::InvalidArguments.into()),
+    };
+
+    if (threshold, requires_reason)
+        != (
+            config.reputation_threshold,
+            config.reputation_requires_reason,
+        )
+    {
+        db.save_reputation_threshold(guild_id, threshold, requires_reason)
+            .await?;
+    }
+
+    let description = match (threshold, requires_reason) {
+        (Some(threshold), false) => format!(
+            "Reports from reporters with less than **{}%** of their reports accepted are flagged.",
+            threshold
+        ),
+        (Some(threshold), true) => format!(
+            "Reporters with less than **{}%** of their reports accepted must give a reason.",
+            threshold
+        ),
+        (None, _) => "Reporter reputation is shown, but never acted on.".to_owned(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Reporter Reputation")
+                    .field("Status", description, false)
+                    .field(
+                        "Update",
+                        format!(
+                            "To change this, use `n!reputation <percent> [flag|reason]` or `n!reputation off`. Reporters need {} resolved reports before this applies.",
+                            MIN_RESOLVED_REPORTS
+                        ),
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ReportBanError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No user was specified")]
+    NoUserSpecified,
+    #[error("Unable to load the user mentioned")]
+    UserLoadError(serenity::Error),
+    #[error("Error parsing user")]
+    UserParseError(#[from] FailedUserParse),
+    #[error("That user isn't banned from making reports")]
+    NotBanned,
+    #[error("Report bans can't last longer than 100 years")]
+    DurationTooLong,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+    #[error("Unable to show the banned users: {0}")]
+    MenuError(#[from] serenity_utils::Error),
+}
+
+/// The longest a report ban may last. Anything longer can't be stored as an
+/// expiry date, and would silently become a permanent ban.
+const MAX_REPORT_BAN: Duration = Duration::from_secs(100 * 365 * 24 * 60 * 60);
+
+async fn parse_report_ban_user(
+    ctx: &Context,
+    msg: &Message,
+    args: &mut Args,
+) -> Result<User, ReportBanError> {
+    let guild = msg.guild(ctx).ok_or(ReportBanError::NoGuild)?;
+
+    let name = args
+        .single_quoted::<String>()
+        .map_err(|_| ReportBanError::NoUserSpecified)?;
+
+    let user = parsing::user(&name, ctx, &guild).await?;
+
+    ctx.http
+        .get_user(user.user_id().0)
+        .await
+        .map_err(ReportBanError::UserLoadError)
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("<user> [duration] [reason]")]
+#[example("@user 7d Spamming reports")]
+#[description(
+    "Bans a user from making reports, optionally for a duration (like `12h`, `7d` or `2w`). The reason is shown to the user."
+)]
+pub async fn reportban(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ReportBanError::NoGuild)?;
+    let user = parse_report_ban_user(ctx, msg, &mut args).await?;
+
+    // the duration is optional, so only consume the argument if it is one
+    let duration = match args.current().and_then(parsing::duration) {
+        Some(duration) => {
+            args.advance();
+            Some(duration)
+        }
+        None => None,
+    };
+
+    if duration.map_or(false, |d| d > MAX_REPORT_BAN) {
+        return Err(ReportBanError::DurationTooLong.into());
+    }
+
+    let reason = Some(args.rest().trim()).filter(|r| !r.is_empty());
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    db.report_ban_user(guild_id, user.id, msg.author.id, reason, duration)
+        .await
+        .map_err(ReportBanError::from)?;
+
+    let ban = db
+        .load_report_ban(&guild_id, &user.id)
+        .await
+        .map_err(ReportBanError::from)?;
+
+    let until = match ban.and_then(|b| b.expires) {
+        Some(expires) => format!("until <t:{}:f>", expires.timestamp()),
+        None => "until they are unbanned".to_owned(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Banned From Reporting")
+                    .field(
+                        "Banned",
+                        format!("{} can't make reports {}.", user.mention(), until),
+                        false,
+                    )
+                    .field("Reason", reason.unwrap_or("*No reason given*"), false)
+                    .field(
+                        "Update",
+                        format!("To lift the ban, use `n!reportunban {}`", user.id),
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("<user>")]
+#[description("Lifts a user's ban from making reports.")]
+pub async fn reportunban(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ReportBanError::NoGuild)?;
+    let user = parse_report_ban_user(ctx, msg, &mut args).await?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    if !db
+        .report_unban_user(guild_id, user.id)
+        .await
+        .map_err(ReportBanError::from)?
+    {
+        return Err(ReportBanError::NotBanned.into());
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Unbanned From Reporting").field(
+                    "Unbanned",
+                    format!("{} can make reports again.", user.mention()),
+                    false,
+                )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// How many bans are shown on each page of `n!reportbans`.
+const REPORT_BANS_PER_PAGE: usize = 10;
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[description("Lists the users banned from making reports.")]
+pub async fn reportbans(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ReportBanError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let bans = db
+        .load_report_bans(&guild_id)
+        .await
+        .map_err(ReportBanError::from)?;
+
+    // the menu waits for button presses, so don't hold onto the database
+    drop(data);
+
+    let mut pages = (bans.chunks(REPORT_BANS_PER_PAGE))
+        .map(|chunk| {
+            let lines = (chunk.iter())
+                .map(|ban| {
+                    let mut line = format!(
+                        "{} by {} <t:{}:R>",
+                        ban.user_id.mention(),
+                        ban.banned_by.mention(),
+                        ban.created.timestamp()
+                    );
+
+                    match ban.expires {
+                        Some(expires) => {
+                            line.push_str(&format!(", ends <t:{}:R>", expires.timestamp()))
+                        }
+                        None => line.push_str(", permanent"),
+                    };
+
+                    if let Some(reason) = &ban.reason {
+                        line.push_str(&format!("\n> {}", view::truncate(reason, 100)));
+                    }
+
+                    line
+                })
+                .collect::<Vec<_>>();
+
+            let mut page = CreateEmbed::default();
+            page.title("Banned From Reporting")
+                .description(lines.join("\n"));
+            page
+        })
+        .collect::<Vec<_>>();
+
+    if pages.is_empty() {
+        let mut page = CreateEmbed::default();
+        page.title("Banned From Reporting")
+            .description("*Nobody is banned from making reports.*");
+        pages.push(page);
+    }
+
+    menu(ctx, msg, &msg.author, &pages, MenuOptions::default())
+        .await
+        .map_err(ReportBanError::from)?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum RateLimitError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected `user`, `server` or `burst`, followed by a count and a duration (like `5 1m`) or `off`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[user|server|burst <count> <duration> | user|server|burst off]")]
+#[description(
+    "Set (or show) how many reports each user and the whole server may make, and how many reports against the same user start a burst that collapses them into one mod view."
+)]
+pub async fn ratelimit(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(RateLimitError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(RateLimitError::UnconfiguredServer)?;
+
+    let mut user_rate_limit = config.user_rate_limit;
+    let mut guild_rate_limit = config.guild_rate_limit;
+    let mut burst = RateLimit {
+        count: config.burst_threshold,
+        per: config.burst_window,
+    };
+
+    if !args.is_empty() {
+        let kind = args.single_quoted::<String>()?.to_lowercase();
+        let limit = match kind.as_str() {
+            "user" => &mut user_rate_limit,
+            "server" => &mut guild_rate_limit,
+            "burst" => &mut burst,
+            _ => return Err(RateLimitError::InvalidArguments.into()),
+        };
+
+        *limit = match args.single_quoted::<String>() {
+            Ok(off) if off.eq_ignore_ascii_case("off") => RateLimit {
+                count: 0,
+                per: limit.per,
+            },
+            Ok(count) => {
+                let count = count
+                    .parse::<u32>()
+                    .map_err(|_| RateLimitError::InvalidArguments)?;
+                let per = (args.single_quoted::<String>().ok())
+                    .and_then(|d| parsing::duration(&d))
+                    .ok_or(RateLimitError::InvalidArguments)?;
+
+                RateLimit { count, per }
+            }
+            Err(_) => return Err(RateLimitError::InvalidArguments.into()),
+        };
+
+        db.save_rate_limits(
+            guild_id,
+            user_rate_limit,
+            guild_rate_limit,
+            burst.count,
+            burst.per,
+        )
+        .await?;
+    }
+
+    fn display_limit(limit: RateLimit, what: &str, per: &str) -> String {
+        match limit.is_enabled() {
+            true => format!(
+                "**{}** {} {} **{}**",
+                limit.count,
+                what,
+                per,
+                view::display_duration(limit.per)
+            ),
+            false => "*Off*".to_owned(),
+        }
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Report Rate Limits")
+                    .field("Per User", display_limit(user_rate_limit, "reports", "every"), true)
+                    .field("Whole Server", display_limit(guild_rate_limit, "reports", "every"), true)
+                    .field(
+                        "Bursts",
+                        display_limit(burst, "reports against the same user", "within"),
+                        true,
+                    )
+                    .field(
+                        "Update",
+                        "To change these, use `n!ratelimit <user|server|burst> <count> <duration>` (like `n!ratelimit user 5 1m`) or `n!ratelimit <user|server|burst> off`.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum CasesError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected a duration (like `30m` or `1h`), or `off`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[off | <duration>]")]
+#[description(
+    "Set (or show) how long reports against the same user are grouped into one case. Reports against the same message are always grouped."
+)]
+pub async fn cases(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(CasesError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(CasesError::UnconfiguredServer)?;
+
+    let case_window = match args.single_quoted::<String>() {
+        Ok(off) if off.eq_ignore_ascii_case("off") => None,
+        Ok(window) => Some(parsing::duration(&window).ok_or(CasesError::InvalidArguments)?),
+        Err(ArgError::Eos) => config.case_window,
+        Err(_) => return Err(CasesError::InvalidArguments.into()),
+    };
+
+    if case_window != config.case_window {
+        db.save_case_window(guild_id, case_window).await?;
+    }
+
+    let description = match case_window {
+        Some(window) => format!(
+            "Open reports against the same message, or against the same user within **{}**, are grouped into one case.",
+            view::display_duration(window)
+        ),
+        None => "Open reports against the same message are grouped into one case.".to_owned(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Report Cases")
+                    .field("Status", description, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!cases <duration>` (like `n!cases 30m`) or `n!cases off`.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ActionsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected `delete`, `timeout`, `kick` or `ban`, followed by `true` or `false`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[delete|timeout|kick|ban true|false]")]
+#[description(
+    "Set (or show) which moderation actions moderators can take when accepting a report. Actions are chosen from the menu on mod views that use buttons."
+)]
+pub async fn actions(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ActionsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(ActionsError::UnconfiguredServer)?;
+
+    let mut moderation_actions = config.moderation_actions;
+
+    if !args.is_empty() {
+        let name = args.single_quoted::<String>()?.to_lowercase();
+        let kind = (ModerationActionKind::ALL.iter())
+            .find(|k| k.name() == name)
+            .ok_or(ActionsError::InvalidArguments)?;
+        let allowed = args
+            .single_quoted::<bool>()
+            .map_err(|_| ActionsError::InvalidArguments)?;
+
+        match allowed {
+            true => moderation_actions |= kind.bit(),
+            false => moderation_actions &= !kind.bit(),
+        };
+
+        if moderation_actions != config.moderation_actions {
+            db.save_moderation_actions(guild_id, moderation_actions)
+                .await?;
+        }
+    }
+
+    let lines = (ModerationActionKind::ALL.iter())
+        .map(|kind| {
+            let status = match moderation_actions & kind.bit() != 0 {
+                true => "✅ allowed",
+                false => "❌ not allowed",
+            };
+            format!("`{}` - {}", kind.name(), status)
+        })
+        .collect::<Vec<_>>();
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Moderation Actions")
+                    .field("Actions", lines.join("\n"), false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!actions <delete|timeout|kick|ban> <true|false>`. Narc needs permission to take the action in this server.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum WarningsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected `on`, `off`, `template <template>` or `template reset`")]
+    InvalidArguments,
+    #[error("The template can't be longer than {0} characters")]
+    TemplateTooLong(usize),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[on|off|template <template>|template reset]")]
+#[description(
+    "Set (or show) whether reported users are warned in their DMs when a report against them is accepted, and what they're told. The reporter is never revealed."
+)]
+pub async fn warnings(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(WarningsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(WarningsError::UnconfiguredServer)?;
+
+    let mut enabled = config.warn_reported_users;
+    let mut template = config.warning_template.clone();
+
+    if !args.is_empty() {
+        match args.single::<String>()?.to_lowercase().as_str() {
+            "on" => enabled = true,
+            "off" => enabled = false,
+            "template" => {
+                template = match args.rest().trim() {
+                    "" => return Err(WarningsError::InvalidArguments.into()),
+                    "reset" => None,
+                    text if text.chars().count() > WARNING_TEMPLATE_MAX_LENGTH => {
+                        return Err(
+                            WarningsError::TemplateTooLong(WARNING_TEMPLATE_MAX_LENGTH).into()
+                        )
+                    }
+                    text => Some(text.to_owned()),
+                }
+            }
+            _ => return Err(WarningsError::InvalidArguments.into()),
+        };
+
+        db.save_warning_dms(guild_id, enabled, template.as_deref())
+            .await?;
+    }
+
+    let status = match enabled {
+        true => "✅ Reported users are warned when a report against them is accepted",
+        false => "❌ Reported users aren't warned",
+    };
+
+    let example = services::render_warning(
+        template.as_deref().unwrap_or(DEFAULT_WARNING_TEMPLATE),
+        &guild_id
+            .name(ctx)
+            .unwrap_or_else(|| "the server".to_owned()),
+        Some("No spam"),
+        Some("Please keep it to the memes channel"),
+    );
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Warnings")
+                    .field("Status", status, false)
+                    .field("Example", example, false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!warnings <on|off>`. To change what reported users are told, use `n!warnings template <template>`, where `{server}`, `{rule}` and `{note}` are filled in, or `n!warnings template reset`. Moderators set the rule and note with ⚠️ Edit Warning on a mod view.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+/// The most categories a server may have, since they're all choices of the
+/// `/report` command.
+const MAX_CATEGORIES: usize = 25;
+
+/// The longest name a category may have, which Discord limits command choices
+/// to.
+const CATEGORY_NAME_MAX_LENGTH: usize = 100;
+
+#[derive(Debug, Error)]
+pub enum CategoriesError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected `add <name> [emoji] [channel] [role]` or `remove <name>`")]
+    InvalidArguments,
+    #[error("`{0}` isn't an emoji, channel or role")]
+    UnknownArgument(String),
+    #[error("Category names can't be longer than {0} characters")]
+    NameTooLong(usize),
+    #[error("A server can't have more than {0} categories")]
+    TooManyCategories(usize),
+    #[error("That emoji is already used by the `{0}` category")]
+    EmojiInUse(String),
+    #[error("There's no category called `{0}`")]
+    UnknownCategory(String),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[add <name> [emoji] [channel] [role]|remove <name>]")]
+#[example("add spam 🥫 #spam-reports @Spam Patrol")]
+#[description(
+    "Set (or show) the categories reports can be made in. Each category can have its own report emoji, and is a choice of the `/report` command. Reports in a category are sent to its channel (or the reports channel), pinging its role."
+)]
+pub async fn categories(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(ctx).ok_or(CategoriesError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild.id)
+        .await?
+        .ok_or(CategoriesError::UnconfiguredServer)?;
+
+    if !args.is_empty() {
+        let subcommand = args.single::<String>()?.to_lowercase();
+        let name = args
+            .single_quoted::<String>()
+            .map_err(|_| CategoriesError::InvalidArguments)?;
+
+        match subcommand.as_str() {
+            "add" => {
+                if name.chars().count() > CATEGORY_NAME_MAX_LENGTH {
+                    return Err(CategoriesError::NameTooLong(CATEGORY_NAME_MAX_LENGTH).into());
+                }
+
+                let is_new = !config.categories.iter().any(|c| c.name == name);
+                if is_new && config.categories.len() >= MAX_CATEGORIES {
+                    return Err(CategoriesError::TooManyCategories(MAX_CATEGORIES).into());
+                }
+
+                let (mut emoji, mut channel_id, mut role_id) = (None, None, None);
+
+                for arg in args.iter::<String>().quoted() {
+                    let arg = arg?;
+
+                    if let Some(&id) = parsing::channel_mention(&arg).first() {
+                        channel_id = Some(id);
+                    } else if let Some(id) = parsing::role(&arg, &guild) {
+                        role_id = Some(id);
+                    } else if let Some(parsed) = parsing::emoji(&arg) {
+                        emoji = Some(parsed);
+                    } else {
+                        return Err(CategoriesError::UnknownArgument(arg).into());
+                    }
+                }
+
+                if let Some(emoji) = &emoji {
+                    if let Some(other) = (config.categories.iter())
+                        .find(|c| c.name != name && c.matches_emoji(emoji))
+                    {
+                        return Err(CategoriesError::EmojiInUse(other.name.clone()).into());
+                    }
+                }
+
+                db.save_report_category(guild.id, &name, emoji.as_ref(), channel_id, role_id)
+                    .await?;
+            }
+            "remove" => {
+                if !db.delete_report_category(guild.id, &name).await? {
+                    return Err(CategoriesError::UnknownCategory(name).into());
+                }
+            }
+            _ => return Err(CategoriesError::InvalidArguments.into()),
+        };
+
+        // the categories are choices of the `/report` command
+        application_commands::register(ctx, guild.id).await;
+    }
+
+    let categories = match db.get_server_config(&guild.id).await? {
+        Some(config) => config.categories,
+        None => Vec::new(),
+    };
+
+    let lines = match categories.is_empty() {
+        true => vec!["*No categories - every report is sent to the reports channel*".to_owned()],
+        false => categories.iter().map(display_category).collect(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Report Categories")
+                    .field("Categories", lines.join("\n"), false)
+                    .field(
+                        "Update",
+                        "To add or change a category, use `n!categories add <name> [emoji] [channel] [role]`. To remove one, use `n!categories remove <name>`. Reports in a removed category become uncategorized.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+fn display_category(category: &ReportCategoryModel) -> String {
+    let mut line = format!("**{}**", category.name);
+
+    if let Some(emoji) = category.display_emoji() {
+        line.push_str(&format!(" {}", emoji));
+    }
+
+    if let Some(channel_id) = category.channel_id {
+        line.push_str(&format!(" → {}", channel_id.mention()));
+    }
+
+    if let Some(role_id) = category.ping_role_id {
+        line.push_str(&format!(" (pings {})", role_id.mention()));
+    }
+
+    line
+}
+
+/// The shortest time a report may be left alone before moderators are
+/// reminded about it, so that reminders can't flood the reports channel.
+const MIN_SLA: Duration = Duration::from_secs(5 * 60);
+
+#[derive(Debug, Error)]
+pub enum AlertsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected `modrole <role>|off`, `adminrole <role>|off`, `unhandled <duration>|off` or `reviewing <duration>|off`")]
+    InvalidArguments,
+    #[error("Reports must be left alone for at least {0} before reminding moderators")]
+    SlaTooShort(String),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+#[command]
+#[required_permissions(ADMINISTRATOR)]
+#[usage("[modrole|adminrole <role>|off] or [unhandled|reviewing <duration>|off]")]
+#[example("unhandled 2h")]
+#[description(
+    "Set (or show) who is pinged about reports. The moderator role is pinged whenever a report is sent to the moderators. Reports left unhandled or under review for too long remind the moderators, and escalate to the admin role if they're still left alone."
+)]
+pub async fn alerts(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(ctx).ok_or(AlertsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = db
+        .get_server_config(&guild.id)
+        .await?
+        .ok_or(AlertsError::UnconfiguredServer)?;
+
+    let mut mod_role_id = config.mod_role_id;
+    let mut admin_role_id = config.admin_role_id;
+    let mut unhandled_sla = config.unhandled_sla;
+    let mut reviewing_sla = config.reviewing_sla;
+
+    if !args.is_empty() {
+        let setting = args.single::<String>()?.to_lowercase();
+        let value = args.rest().trim();
+        let is_off = value.eq_ignore_ascii_case("off");
+
+        match setting.as_str() {
+            "modrole" | "adminrole" => {
+                let role_id = match is_off {
+                    true => None,
+                    false => {
+                        Some(parsing::role(value, &guild).ok_or(AlertsError::InvalidArguments)?)
+                    }
+                };
+
+                match setting.as_str() {
+                    "modrole" => mod_role_id = role_id,
+                    _ => admin_role_id = role_id,
+                };
+            }
+            "unhandled" | "reviewing" => {
+                let sla = match is_off {
+                    true => None,
+                    false => Some(parsing::duration(value).ok_or(AlertsError::InvalidArguments)?),
+                };
+
+                if sla.map_or(false, |sla| sla < MIN_SLA) {
+                    return Err(AlertsError::SlaTooShort(view::display_duration(MIN_SLA)).into());
+                }
+
+                match setting.as_str() {
+                    "unhandled" => unhandled_sla = sla,
+                    _ => reviewing_sla = sla,
+                };
+            }
+            _ => return Err(AlertsError::InvalidArguments.into()),
+        };
+
+        db.save_escalation(
+            guild.id,
+            mod_role_id,
+            admin_role_id,
+            unhandled_sla,
+            reviewing_sla,
+        )
+        .await?;
+    }
+
+    let display_role = |role_id: Option<RoleId>| match role_id {
+        Some(role_id) => role_id.mention().to_string(),
+        None => "*None*".to_owned(),
+    };
+    let display_sla = |sla: Option<Duration>| match sla {
+        Some(sla) => format!("after {}", view::display_duration(sla)),
+        None => "*Never*".to_owned(),
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Alerts")
+                    .field("Moderator Role", display_role(mod_role_id), true)
+                    .field("Admin Role", display_role(admin_role_id), true)
+                    .field("Remind About Unhandled Reports", display_sla(unhandled_sla), false)
+                    .field("Remind About Reports Under Review", display_sla(reviewing_sla), false)
+                    .field(
+                        "Update",
+                        "To change this, use `n!alerts <modrole|adminrole> <role|off>` or `n!alerts <unhandled|reviewing> <duration|off>`. The first reminder pings the moderator role (or DMs the moderator reviewing the report), and later reminders ping the admin role.",
+                        false,
+                    )
+            })
+        })
+        .await?;
+
+    Ok(())
+}
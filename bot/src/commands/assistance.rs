@@ -1,13 +1,26 @@
+use std::time::Duration;
+
 use serenity::{
     client::Context,
     framework::standard::{macros::*, Args, CommandResult},
-    model::channel::Message,
+    model::{
+        application::interaction::InteractionResponseType,
+        channel::{Channel, GuildChannel, Message, ReactionType},
+        guild::{Guild, Member},
+        id::GuildId,
+        permissions::Permissions,
+    },
+    prelude::Mentionable,
 };
 
 use thiserror::Error;
 
-use crate::{database::Database, parsing::FailedUserParse, services::MakeReportError};
-use crate::{parsing, services};
+use crate::{
+    database::Database,
+    parsing::FailedUserParse,
+    services::{MakeReportError, REASON_MAX_LENGTH},
+};
+use crate::{parsing, serenity_utils, services, view};
 
 #[derive(Debug, Error)]
 pub enum ReportCommandError {
@@ -17,6 +30,8 @@ pub enum ReportCommandError {
     UserParseError(#[from] FailedUserParse),
     #[error("Error occured while making report")]
     MakeReportError(#[from] MakeReportError),
+    #[error("A reason can't be longer than {} characters", REASON_MAX_LENGTH)]
+    ReasonTooLong,
 }
 
 #[command]
@@ -36,6 +51,10 @@ pub async fn report(ctx: &Context, msg: &Message, mut args: Args) -> CommandResu
 
     let reason = args.remains();
 
+    if reason.map_or(false, |r| r.chars().count() > REASON_MAX_LENGTH) {
+        return Err(ReportCommandError::ReasonTooLong.into());
+    }
+
     services::make_report(
         &ctx,
         &db,
@@ -43,10 +62,369 @@ pub async fn report(ctx: &Context, msg: &Message, mut args: Args) -> CommandResu
         msg.author.id,
         user.user_id(),
         None,
-        None,
         reason,
+        None,
+    )
+    .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum TicketError {
+    #[error("You aren't in any server that takes reports")]
+    NoMutualServers,
+    #[error("That server is no longer available")]
+    UnknownServer,
+    #[error("You took too long to answer, so the ticket was cancelled")]
+    Timeout,
+    #[error("The ticket was cancelled")]
+    Cancelled,
+    #[error("A reason is required")]
+    NoReason,
+    #[error("A reason can't be longer than {} characters", REASON_MAX_LENGTH)]
+    ReasonTooLong,
+    #[error("Error parsing user")]
+    UserParseError(#[from] FailedUserParse),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+    #[error("Unable to confirm the ticket: {0}")]
+    PromptError(#[from] serenity_utils::Error),
+    #[error("Error occured while making report")]
+    MakeReportError(#[from] MakeReportError),
+}
+
+/// How long to wait for each answer while filing a ticket, in seconds.
+const TICKET_TIMEOUT: f32 = 300.0;
+
+/// The most messages and screenshots a ticket may have as evidence.
+const MAX_EVIDENCE: usize = 10;
+
+/// Discord allows at most this many options in a select menu.
+const MAX_SERVER_OPTIONS: usize = 25;
+
+/// The most servers Discord is asked about whether the reporter is in them,
+/// when their membership isn't cached.
+const MAX_MEMBER_LOOKUPS: usize = 10;
+
+const TICKET_SERVER_SELECT: &str = "ticket_server";
+
+#[command]
+#[only_in(dms)]
+#[description(
+    "Files a report through DMs, for when there's no single message to report. You'll be asked which server it's for, who you're reporting and why, and can then send screenshots and links to messages as evidence. Type `cancel` at any point to stop."
+)]
+pub async fn ticket(ctx: &Context, msg: &Message) -> CommandResult {
+    let guild_id = choose_server(ctx, msg).await?;
+    let guild = (guild_id.to_guild_cached(ctx)).ok_or(TicketError::UnknownServer)?;
+
+    ask(
+        ctx,
+        msg,
+        "Ticket (2/4)",
+        "Reported User",
+        "Who are you reporting? Type their name, mention or ID.",
+    )
+    .await?;
+    let name = answer(ctx, msg).await?.content;
+    let reported = parsing::user(name.trim(), ctx, &guild)
+        .await
+        .map_err(TicketError::from)?;
+
+    ask(
+        ctx,
+        msg,
+        "Ticket (3/4)",
+        "Reason",
+        "Why are you reporting them?",
+    )
+    .await?;
+    let reason = answer(ctx, msg).await?.content;
+    let reason = reason.trim();
+
+    if reason.is_empty() {
+        return Err(TicketError::NoReason.into());
+    }
+
+    if reason.chars().count() > REASON_MAX_LENGTH {
+        return Err(TicketError::ReasonTooLong.into());
+    }
+
+    let evidence = collect_evidence(ctx, msg, &guild).await?;
+
+    let confirmation = msg
+        .channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Ticket Confirmation")
+                    .field("Server", &guild.name, true)
+                    .field("Reported User", reported.user_id().mention(), true)
+                    .field("Evidence", format!("{} item(s)", evidence.len()), true)
+                    .field("Reason", view::truncate(reason, 1024), false)
+            })
+        })
+        .await?;
+
+    let confirmed =
+        serenity_utils::prompt::yes_or_no_prompt(ctx, &confirmation, &msg.author, TICKET_TIMEOUT)
+            .await
+            .map_err(TicketError::from)?;
+
+    if !confirmed {
+        return Err(TicketError::Cancelled.into());
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report_id = services::make_report(
+        ctx,
+        db,
+        guild_id,
+        msg.author.id,
+        reported.user_id(),
+        None,
+        Some(reason),
+        None,
+    )
+    .await
+    .map_err(TicketError::from)?;
+
+    if let (Some(report_id), false) = (report_id, evidence.is_empty()) {
+        services::add_report_evidence(ctx, db, guild_id, report_id, &evidence)
+            .await
+            .map_err(TicketError::from)?;
+    }
+
+    Ok(())
+}
+
+/// Asks which server the ticket is for, out of the configured servers the
+/// reporter is in. There's nothing to ask if they're only in one.
+async fn choose_server(ctx: &Context, msg: &Message) -> Result<GuildId, TicketError> {
+    let mut servers = Vec::new();
+
+    {
+        let data = ctx.data.read().await;
+        let db = data.get::<Database>().unwrap();
+
+        let mut lookups = 0;
+
+        for guild_id in ctx.cache.guilds() {
+            if db.get_server_config(&guild_id).await?.is_none() {
+                continue;
+            }
+
+            // members aren't always cached, in which case Discord is asked,
+            // but only about so many servers
+            let is_member = match ctx.cache.member(guild_id, msg.author.id) {
+                Some(_) => true,
+                None if lookups < MAX_MEMBER_LOOKUPS => {
+                    lookups += 1;
+                    ctx.http
+                        .get_member(guild_id.0, msg.author.id.0)
+                        .await
+                        .is_ok()
+                }
+                None => false,
+            };
+
+            if is_member {
+                let name = (guild_id.name(ctx)).unwrap_or_else(|| guild_id.to_string());
+                servers.push((guild_id, name));
+            }
+        }
+    }
+
+    servers.sort_by(|(_, a), (_, b)| a.to_lowercase().cmp(&b.to_lowercase()));
+    servers.truncate(MAX_SERVER_OPTIONS);
+
+    match servers.as_slice() {
+        [] => return Err(TicketError::NoMutualServers),
+        [(guild_id, _)] => return Ok(*guild_id),
+        _ => {}
+    };
+
+    let prompt = msg
+        .channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title("Ticket (1/4)")
+                    .field("Server", "Which server is your report for?", false)
+            })
+            .components(|c| {
+                c.create_action_row(|row| {
+                    row.create_select_menu(|menu| {
+                        menu.custom_id(TICKET_SERVER_SELECT)
+                            .placeholder("Choose a server...")
+                            .options(|o| {
+                                for (guild_id, name) in &servers {
+                                    o.create_option(|opt| {
+                                        opt.label(view::truncate(name, 100))
+                                            .value(guild_id.to_string())
+                                    });
+                                }
+                                o
+                            })
+                    })
+                })
+            })
+        })
+        .await?;
+
+    let interaction = prompt
+        .await_component_interaction(ctx)
+        .author_id(msg.author.id)
+        .timeout(Duration::from_secs_f32(TICKET_TIMEOUT))
+        .await
+        .ok_or(TicketError::Timeout)?;
+
+    let (guild_id, name) = (interaction.data.values.first())
+        .and_then(|value| servers.iter().find(|(id, _)| id.to_string() == *value))
+        .ok_or(TicketError::UnknownServer)?;
+
+    // the menu is replaced with the chosen server
+    interaction
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::UpdateMessage)
+                .interaction_response_data(|d| {
+                    d.embed(|e| e.title("Ticket (1/4)").field("Server", name, false))
+                        .components(|c| c)
+                })
+        })
+        .await?;
+
+    Ok(*guild_id)
+}
+
+/// Collects screenshots, and messages in the server the reporter links to,
+/// until they're done. Reporters can only link to messages in channels they
+/// can read.
+async fn collect_evidence(
+    ctx: &Context,
+    msg: &Message,
+    guild: &Guild,
+) -> Result<Vec<(Message, bool)>, TicketError> {
+    let member = guild.member(ctx, msg.author.id).await?;
+
+    ask(
+        ctx,
+        msg,
+        "Ticket (4/4)",
+        "Evidence",
+        &format!(
+            "Send screenshots, or links to messages in the server (up to {}). Type `done` when you're finished.",
+            MAX_EVIDENCE
+        ),
     )
     .await?;
 
+    let mut evidence = Vec::new();
+
+    while evidence.len() < MAX_EVIDENCE {
+        let answer = answer(ctx, msg).await?;
+
+        if answer.content.trim().eq_ignore_ascii_case("done") {
+            break;
+        }
+
+        let before = evidence.len();
+        let mut missing = 0;
+
+        for (_, channel_id, message_id) in parsing::message_links(&answer.content) {
+            // the link's server could be anything, so the channel is checked
+            let linked = match channel_id.to_channel(ctx).await {
+                Ok(Channel::Guild(channel)) if can_read(guild, &channel, &member) => {
+                    channel_id.message(ctx, message_id).await.ok()
+                }
+                _ => None,
+            };
+
+            match linked {
+                Some(linked) => evidence.push((linked, true)),
+                _ => missing += 1,
+            }
+        }
+
+        if !answer.attachments.is_empty() {
+            evidence.push((answer.clone(), false));
+        }
+
+        if missing > 0 {
+            msg.channel_id
+                .say(
+                    ctx,
+                    format!(
+                        "Couldn't find {} of the linked messages in channels you can see in the server you're reporting to.",
+                        missing
+                    ),
+                )
+                .await?;
+        } else if evidence.len() == before {
+            msg.channel_id
+                .say(
+                    ctx,
+                    "Send a screenshot or a message link, or type `done` when you're finished.",
+                )
+                .await?;
+        }
+
+        if evidence.len() > before {
+            answer
+                .react(ctx, ReactionType::Unicode("✅".to_owned()))
+                .await?;
+        }
+    }
+
+    evidence.truncate(MAX_EVIDENCE);
+
+    Ok(evidence)
+}
+
+/// Checks if a member can read the messages in a channel of the server.
+fn can_read(guild: &Guild, channel: &GuildChannel, member: &Member) -> bool {
+    let needed = Permissions::VIEW_CHANNEL | Permissions::READ_MESSAGE_HISTORY;
+
+    // threads don't have permissions of their own, so the channel they're in
+    // is checked instead
+    let channel = match (&channel.thread_metadata, channel.parent_id) {
+        (Some(_), Some(parent_id)) => match guild.channels.get(&parent_id) {
+            Some(Channel::Guild(parent)) => parent,
+            _ => return false,
+        },
+        _ => channel,
+    };
+
+    channel.guild_id == guild.id
+        && (guild.user_permissions_in(channel, member)).map_or(false, |p| p.contains(needed))
+}
+
+async fn ask(
+    ctx: &Context,
+    msg: &Message,
+    title: &str,
+    name: &str,
+    question: &str,
+) -> Result<(), TicketError> {
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| e.title(title).field(name, question, false))
+        })
+        .await?;
+
     Ok(())
 }
+
+/// Waits for the reporter's next message, unless they cancel the ticket.
+async fn answer(ctx: &Context, msg: &Message) -> Result<Message, TicketError> {
+    let answer = serenity_utils::prompt::message_prompt(ctx, msg, &msg.author, TICKET_TIMEOUT)
+        .await
+        .ok_or(TicketError::Timeout)?;
+
+    match answer.content.trim().eq_ignore_ascii_case("cancel") {
+        true => Err(TicketError::Cancelled),
+        false => Ok(answer),
+    }
+}
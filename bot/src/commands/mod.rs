@@ -11,17 +11,43 @@ use assistance::*;
 mod administration;
 use administration::*;
 
+mod moderation;
+use moderation::*;
+
 mod help;
 pub use help::*;
 
 #[group("Assistance")]
 #[description = "Commands that serve to aid users in getting assistance"]
-#[commands(report)]
+#[commands(report, ticket)]
 pub struct Assistance;
 
+#[group("Moderation")]
+#[description = "Commands that help moderators handle reports"]
+#[commands(reports, profile, revisions, history, note, reply)]
+pub struct Moderation;
+
 #[group("Administration")]
 #[description = "Commands that are for administrator use only"]
-#[commands(setup, protect, protected)]
+#[commands(
+    setup,
+    protect,
+    protected,
+    archival,
+    controls,
+    threads,
+    relay,
+    reputation,
+    reportban,
+    reportunban,
+    reportbans,
+    ratelimit,
+    cases,
+    actions,
+    warnings,
+    categories,
+    alerts
+)]
 pub struct Administration;
 
 #[hook]
@@ -0,0 +1,766 @@
+use serenity::{
+    builder::CreateEmbed,
+    client::Context,
+    framework::standard::{macros::*, Args, CommandResult},
+    model::{channel::Message, id::UserId},
+    prelude::Mentionable,
+    utils::{content_safe, parse_channel, ContentSafeOptions},
+};
+use sqlx::types::chrono::{DateTime, NaiveDate, Utc};
+
+use thiserror::Error;
+
+use crate::{
+    database::{
+        models::{ReportEventKind, ReportEventModel, ReportFilter, ReportModel, ReportStatus},
+        Database,
+    },
+    parsing::{self, FailedUserParse},
+    serenity_utils::{
+        self,
+        menu::{menu, MenuOptions},
+    },
+    services::{self, NOTE_MAX_LENGTH, RELAY_MAX_LENGTH},
+    view,
+};
+
+#[derive(Debug, Error)]
+pub enum RevisionsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No report ID was specified")]
+    NoReportSpecified,
+    #[error("There is no report #{0} in this server")]
+    UnknownReport(u64),
+    #[error("Report #{0} isn't about a message")]
+    NoReportedMessage(u64),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+/// How many of the most recent revisions to show, as embeds are limited in
+/// size.
+const MAX_REVISIONS: usize = 8;
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<report id>")]
+#[description("Shows every revision of a reported message, as it was edited over time.")]
+pub async fn revisions(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(RevisionsError::NoGuild)?;
+    let report_id = args
+        .single::<u64>()
+        .map_err(|_| RevisionsError::NoReportSpecified)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .filter(|r| r.guild_id == guild_id)
+        .ok_or(RevisionsError::UnknownReport(report_id))?;
+
+    let message_id = report
+        .message_id
+        .ok_or(RevisionsError::NoReportedMessage(report_id))?;
+
+    let history = db.load_message_history(&message_id).await?;
+
+    let mut description = match report.url() {
+        Some(url) => format!("Revisions of the [reported message]({}).", url),
+        None => "Revisions of the reported message.".to_owned(),
+    };
+
+    if let Some(deleted) = history.deleted {
+        description.push_str(&format!(
+            "\n🗑️ The message was deleted <t:{}:R>.",
+            deleted.timestamp()
+        ));
+    }
+
+    let skipped = history.revisions.len().saturating_sub(MAX_REVISIONS);
+    if skipped > 0 {
+        description.push_str(&format!(
+            "\nOnly the {} most recent revisions are shown.",
+            MAX_REVISIONS
+        ));
+    }
+
+    let fields = (history.revisions.iter().enumerate().skip(skipped))
+        .map(|(idx, revision)| {
+            let name = match revision.archived {
+                Some(archived) => format!("Revision {} (<t:{}:f>)", idx + 1, archived.timestamp()),
+                None => format!("Revision {}", idx + 1),
+            };
+
+            let content = content_safe(ctx, &revision.content, &ContentSafeOptions::default(), &[]);
+            let mut value = view::truncate(&content, 512);
+
+            if value.trim().is_empty() {
+                value = "*No text content*".to_owned();
+            }
+
+            if revision.attachment_count > 0 {
+                value.push_str(&format!("\n📎 {} attachment(s)", revision.attachment_count));
+            }
+
+            (name, value, false)
+        })
+        .collect::<Vec<_>>();
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(format!("Message History (Report #{})", report.id))
+                    .description(description)
+                    .fields(fields)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum HistoryError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No report ID was specified")]
+    NoReportSpecified,
+    #[error("There is no report #{0} in this server")]
+    UnknownReport(u64),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+/// The longest description Discord allows an embed to have.
+const MAX_DESCRIPTION_LENGTH: usize = 4096;
+
+/// Room left in the description for the note that older events were left out.
+const SKIPPED_NOTE_LENGTH: usize = 64;
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<report id>")]
+#[description("Shows everything that happened to a report, and who did it.")]
+pub async fn history(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(HistoryError::NoGuild)?;
+    let report_id = args
+        .single::<u64>()
+        .map_err(|_| HistoryError::NoReportSpecified)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .filter(|r| r.guild_id == guild_id)
+        .ok_or(HistoryError::UnknownReport(report_id))?;
+
+    let events = db.load_report_events(report.id).await?;
+
+    // as many of the most recent events as fit in the embed are shown
+    let mut lines = Vec::new();
+    let mut length = 0;
+
+    for event in events.iter().rev() {
+        let description = display_event(ctx, event);
+        let line = format!("<t:{}:f> {}", event.created.timestamp(), description);
+
+        length += line.chars().count() + 1;
+        if length > MAX_DESCRIPTION_LENGTH - SKIPPED_NOTE_LENGTH {
+            break;
+        }
+
+        lines.push(line);
+    }
+
+    lines.reverse();
+
+    if events.is_empty() {
+        lines.push("*No events were recorded for this report.*".to_owned());
+    } else if lines.len() < events.len() {
+        lines.insert(
+            0,
+            format!("*Only the {} most recent events are shown.*", lines.len()),
+        );
+    }
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.title(format!("Report History (Report #{})", report.id))
+                    .description(lines.join("\n"))
+            })
+        })
+        .await?;
+
+    Ok(())
+}
+
+fn display_event(ctx: &Context, event: &ReportEventModel) -> String {
+    let actor = match event.actor {
+        Some(actor) => actor.mention().to_string(),
+        None => "Someone".to_owned(),
+    };
+
+    let status = |value: &Option<String>| match value.as_ref().and_then(|v| v.parse::<i64>().ok()) {
+        Some(status) => ReportStatus::from(status).into_human_status(),
+        None => "unknown",
+    };
+
+    let user = |value: &Option<String>| {
+        value
+            .as_ref()
+            .and_then(|v| v.parse::<u64>().ok())
+            .map(UserId)
+    };
+
+    let quote = |value: &Option<String>| match value {
+        Some(value) => {
+            let value = content_safe(ctx, value, &ContentSafeOptions::default(), &[]);
+            format!("\"{}\"", view::truncate(&value, 100))
+        }
+        None => "nothing".to_owned(),
+    };
+
+    match event.kind {
+        ReportEventKind::Created => match event.new_value {
+            Some(_) => format!("🚩 {} made the report: {}", actor, quote(&event.new_value)),
+            None => format!("🚩 {} made the report", actor),
+        },
+        ReportEventKind::ReasonChanged => format!(
+            "📝 {} changed the reason from {} to {}",
+            actor,
+            quote(&event.old_value),
+            quote(&event.new_value)
+        ),
+        ReportEventKind::StatusChanged => format!(
+            "{} changed the status from {} to {}",
+            actor,
+            status(&event.old_value),
+            status(&event.new_value)
+        ),
+        ReportEventKind::HandlerChanged => match (user(&event.old_value), user(&event.new_value)) {
+            (_, Some(handler)) if Some(handler) == event.actor => {
+                format!("👀 {} claimed the report", actor)
+            }
+            (_, Some(handler)) => {
+                format!("👀 {} assigned the report to {}", actor, handler.mention())
+            }
+            (Some(handler), None) if Some(handler) == event.actor => {
+                format!("👋 {} unclaimed the report", actor)
+            }
+            (_, None) => format!("👋 {} unassigned the report", actor),
+        },
+        ReportEventKind::MessageEdited => {
+            format!("✏️ {} edited the reported message", actor)
+        }
+        ReportEventKind::MessageDeleted => "🗑️ The reported message was deleted".to_owned(),
+        ReportEventKind::NoteAdded => {
+            format!("🗒️ {} added a note: {}", actor, quote(&event.new_value))
+        }
+        ReportEventKind::ActionTaken => format!(
+            "{} (by {})",
+            event.new_value.as_deref().unwrap_or("Took an action"),
+            actor
+        ),
+        ReportEventKind::WarningSent => {
+            format!("⚠️ {} warned the reported user", actor)
+        }
+        ReportEventKind::RelayedToReporter => {
+            format!(
+                "📤 {} messaged the reporter: {}",
+                actor,
+                quote(&event.new_value)
+            )
+        }
+        ReportEventKind::RelayedFromReporter => {
+            format!("📥 {} answered: {}", actor, quote(&event.new_value))
+        }
+    }
+}
+
+#[derive(Debug, Error)]
+pub enum NoteError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No report ID was specified")]
+    NoReportSpecified,
+    #[error("There is no report #{0} in this server")]
+    UnknownReport(u64),
+    #[error("No note was specified")]
+    NoNoteSpecified,
+    #[error("Notes can't be longer than {0} characters")]
+    NoteTooLong(usize),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("An error occurred while adding the note: {0}")]
+    ServiceError(#[from] services::MakeReportError),
+}
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<report id> <note>")]
+#[description(
+    "Attaches a private note to a report, which only moderators can see on the mod view."
+)]
+pub async fn note(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(NoteError::NoGuild)?;
+    let report_id = args
+        .single::<u64>()
+        .map_err(|_| NoteError::NoReportSpecified)?;
+
+    let note = args.rest().trim();
+
+    if note.is_empty() {
+        return Err(NoteError::NoNoteSpecified.into());
+    }
+
+    if note.chars().count() > NOTE_MAX_LENGTH {
+        return Err(NoteError::NoteTooLong(NOTE_MAX_LENGTH).into());
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .filter(|r| r.guild_id == guild_id)
+        .ok_or(NoteError::UnknownReport(report_id))?;
+
+    services::add_report_note(ctx, db, report.id, msg.author.id, note)
+        .await
+        .map_err(NoteError::from)?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ReplyError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No report ID was specified")]
+    NoReportSpecified,
+    #[error("There is no report #{0} in this server")]
+    UnknownReport(u64),
+    #[error("No message was specified")]
+    NoMessageSpecified,
+    #[error("Messages can't be longer than {0} characters")]
+    MessageTooLong(usize),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("An error occurred while sending the message: {0}")]
+    ServiceError(#[from] services::MakeReportError),
+}
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<report id> <message>")]
+#[description(
+    "Sends a message to the person that made a report, such as to ask for more information. Unless the server shows moderators by name, they won't know who sent it. Their answers show up in the report's thread, where you can reply to them directly."
+)]
+pub async fn reply(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild_id = msg.guild_id.ok_or(ReplyError::NoGuild)?;
+    let report_id = args
+        .single::<u64>()
+        .map_err(|_| ReplyError::NoReportSpecified)?;
+
+    let content = args.rest().trim();
+
+    if content.is_empty() {
+        return Err(ReplyError::NoMessageSpecified.into());
+    }
+
+    if content.chars().count() > RELAY_MAX_LENGTH {
+        return Err(ReplyError::MessageTooLong(RELAY_MAX_LENGTH).into());
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .filter(|r| r.guild_id == guild_id)
+        .ok_or(ReplyError::UnknownReport(report_id))?;
+
+    services::relay_to_reporter(ctx, db, &report, &msg.author, content)
+        .await
+        .map_err(ReplyError::from)?;
+
+    Ok(())
+}
+
+#[derive(Debug, Error)]
+pub enum ReportsError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No status was specified (expected unhandled, reviewing, accepted or denied)")]
+    NoStatusSpecified,
+    #[error("Unknown status '{0}' (expected unhandled, reviewing, accepted or denied)")]
+    UnknownStatus(String),
+    #[error("No user was specified")]
+    NoUserSpecified,
+    #[error("Unable to find user: {0}")]
+    UserParseError(#[from] FailedUserParse),
+    #[error("No channel was specified")]
+    NoChannelSpecified,
+    #[error("No date range was specified (expected two dates, like 2021-05-01 2021-06-01)")]
+    NoDateRangeSpecified,
+    #[error("Invalid date '{0}' (expected a date like 2021-05-01)")]
+    InvalidDate(String),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("Unable to show the reports: {0}")]
+    MenuError(#[from] serenity_utils::Error),
+}
+
+/// At most this many reports are found by a search, as the results are held
+/// in memory while they're paginated.
+const MAX_SEARCH_RESULTS: u64 = 100;
+
+/// How many reports are shown on each page of the search results.
+const REPORTS_PER_PAGE: usize = 10;
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[sub_commands(
+    reports_status,
+    reports_user,
+    reports_by,
+    reports_handler,
+    reports_channel,
+    reports_between
+)]
+#[description(
+    "Lists the most recent reports in the server. Use a subcommand to search for specific reports."
+)]
+pub async fn reports(ctx: &Context, msg: &Message) -> CommandResult {
+    show_reports(
+        ctx,
+        msg,
+        ReportFilter::All,
+        "The most recent reports.".to_owned(),
+    )
+    .await?;
+    Ok(())
+}
+
+#[command("status")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<unhandled|reviewing|accepted|denied>")]
+#[description("Lists reports with a status.")]
+pub async fn reports_status(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let status = args
+        .single::<String>()
+        .map_err(|_| ReportsError::NoStatusSpecified)?;
+
+    let status = match status.to_lowercase().as_str() {
+        "unhandled" => ReportStatus::Unhandled,
+        "reviewing" => ReportStatus::Reviewing,
+        "accepted" => ReportStatus::Accepted,
+        "denied" => ReportStatus::Denied,
+        _ => return Err(ReportsError::UnknownStatus(status).into()),
+    };
+
+    let heading = format!("Reports that are {}.", status.into_human_status());
+    show_reports(ctx, msg, ReportFilter::Status(status), heading).await?;
+    Ok(())
+}
+
+#[command("user")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<user>")]
+#[description("Lists reports against a user.")]
+pub async fn reports_user(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let user_id = parse_user_arg(ctx, msg, &mut args).await?;
+
+    let heading = format!("Reports against {}.", user_id.mention());
+    show_reports(ctx, msg, ReportFilter::ReportedUser(user_id), heading).await?;
+    Ok(())
+}
+
+#[command("by")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<user>")]
+#[description("Lists reports made by a user.")]
+pub async fn reports_by(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let user_id = parse_user_arg(ctx, msg, &mut args).await?;
+
+    let heading = format!("Reports made by {}.", user_id.mention());
+    show_reports(ctx, msg, ReportFilter::Accuser(user_id), heading).await?;
+    Ok(())
+}
+
+#[command("handler")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<user>")]
+#[description("Lists reports handled by a moderator.")]
+pub async fn reports_handler(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let user_id = parse_user_arg(ctx, msg, &mut args).await?;
+
+    let heading = format!("Reports handled by {}.", user_id.mention());
+    show_reports(ctx, msg, ReportFilter::Handler(user_id), heading).await?;
+    Ok(())
+}
+
+#[command("channel")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<channel>")]
+#[description("Lists reports about messages in a channel.")]
+pub async fn reports_channel(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let channel = args
+        .single::<String>()
+        .map_err(|_| ReportsError::NoChannelSpecified)?;
+
+    let channel_id = (parse_channel(&channel))
+        .or_else(|| channel.parse::<u64>().ok())
+        .ok_or(ReportsError::NoChannelSpecified)?
+        .into();
+
+    let heading = format!("Reports in <#{}>.", channel_id);
+    show_reports(ctx, msg, ReportFilter::Channel(channel_id), heading).await?;
+    Ok(())
+}
+
+#[command("between")]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<start date> <end date>")]
+#[example("2021-05-01 2021-05-31")]
+#[description("Lists reports made between two dates (inclusive, in UTC).")]
+pub async fn reports_between(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let start = args
+        .single::<String>()
+        .map_err(|_| ReportsError::NoDateRangeSpecified)?;
+    let end = args
+        .single::<String>()
+        .map_err(|_| ReportsError::NoDateRangeSpecified)?;
+
+    let parse_date = |date: &str| {
+        NaiveDate::parse_from_str(date, "%Y-%m-%d")
+            .map_err(|_| ReportsError::InvalidDate(date.to_owned()))
+    };
+
+    let (start, end) = (parse_date(&start)?, parse_date(&end)?);
+
+    // the end date is inclusive, so search up until the day after it
+    let after_end = (end.succ_opt()).ok_or_else(|| ReportsError::InvalidDate(end.to_string()))?;
+
+    let midnight = |date: NaiveDate| {
+        // every day has a midnight
+        DateTime::<Utc>::from_utc(date.and_hms_opt(0, 0, 0).unwrap(), Utc)
+    };
+
+    let filter = ReportFilter::Created(midnight(start), midnight(after_end));
+
+    let heading = format!("Reports made from {} to {}.", start, end);
+    show_reports(ctx, msg, filter, heading).await?;
+    Ok(())
+}
+
+async fn parse_user_arg(
+    ctx: &Context,
+    msg: &Message,
+    args: &mut Args,
+) -> Result<UserId, ReportsError> {
+    let guild = msg.guild(ctx).ok_or(ReportsError::NoGuild)?;
+
+    let name = args
+        .single_quoted::<String>()
+        .map_err(|_| ReportsError::NoUserSpecified)?;
+
+    Ok(parsing::user(&name, ctx, &guild).await?.user_id())
+}
+
+async fn show_reports(
+    ctx: &Context,
+    msg: &Message,
+    filter: ReportFilter,
+    heading: String,
+) -> Result<(), ReportsError> {
+    let guild_id = msg.guild_id.ok_or(ReportsError::NoGuild)?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let reports = db
+        .search_reports(&guild_id, &filter, MAX_SEARCH_RESULTS)
+        .await?;
+
+    // the menu waits for button presses, so don't hold onto the database
+    drop(data);
+
+    let mut pages = (reports.chunks(REPORTS_PER_PAGE))
+        .map(|chunk| {
+            let lines = chunk.iter().map(display_report_line).collect::<Vec<_>>();
+
+            let mut page = CreateEmbed::default();
+            page.title("Reports")
+                .description(format!("{}\n\n{}", heading, lines.join("\n")));
+            page
+        })
+        .collect::<Vec<_>>();
+
+    if pages.is_empty() {
+        let mut page = CreateEmbed::default();
+        page.title("Reports")
+            .description(format!("{}\n\n*No reports were found.*", heading));
+        pages.push(page);
+    }
+
+    if reports.len() as u64 == MAX_SEARCH_RESULTS {
+        for page in pages.iter_mut() {
+            page.footer(|f| {
+                f.text(format!(
+                    "Only the {} most recent reports are shown.",
+                    MAX_SEARCH_RESULTS
+                ))
+            });
+        }
+    }
+
+    menu(ctx, msg, &msg.author, &pages, MenuOptions::default()).await?;
+
+    Ok(())
+}
+
+fn display_report_line(report: &ReportModel) -> String {
+    let mut line = format!(
+        "**#{}** {} {} reported by {}",
+        report.id,
+        report.status.into_human_status(),
+        report.reported_user_id.mention(),
+        report.accuser_user_id.mention()
+    );
+
+    if let Some(created_at) = report.created_at {
+        line.push_str(&format!(" <t:{}:R>", created_at.timestamp()));
+    }
+
+    if let Some(url) = report.url() {
+        line.push_str(&format!(" ([message]({}))", url));
+    }
+
+    line
+}
+
+#[derive(Debug, Error)]
+pub enum ProfileError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("No user was specified")]
+    NoUserSpecified,
+    #[error("Unable to find user: {0}")]
+    UserParseError(#[from] FailedUserParse),
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+}
+
+/// How many of the most recent reports against a user to show.
+const PROFILE_RECENT_REPORTS: u64 = 5;
+
+#[command]
+#[only_in(guilds)]
+#[required_permissions(MANAGE_MESSAGES)]
+#[usage("<user>")]
+#[description("Shows how often a user has been reported, and their most recent reports.")]
+pub async fn profile(ctx: &Context, msg: &Message, mut args: Args) -> CommandResult {
+    let guild = msg.guild(ctx).ok_or(ProfileError::NoGuild)?;
+
+    let name = args
+        .single_quoted::<String>()
+        .map_err(|_| ProfileError::NoUserSpecified)?;
+
+    // users that left the server can still be looked up by their ID
+    let user_id = match name.parse::<u64>() {
+        Ok(id) => UserId(id),
+        Err(_) => parsing::user(&name, ctx, &guild)
+            .await
+            .map_err(ProfileError::from)?
+            .user_id(),
+    };
+
+    let user = user_id.to_user(ctx).await?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let summary = db.load_reported_user_summary(&guild.id, &user_id).await?;
+    let recent = db
+        .search_reports(
+            &guild.id,
+            &ReportFilter::ReportedUser(user_id),
+            PROFILE_RECENT_REPORTS,
+        )
+        .await?;
+
+    let mut recent_lines = Vec::with_capacity(recent.len());
+
+    for report in &recent {
+        let mut line = format!("**#{}** {}", report.id, report.status.into_human_status());
+
+        if let Some(created_at) = report.created_at {
+            line.push_str(&format!(" <t:{}:R>", created_at.timestamp()));
+        }
+
+        if let Some(view) = db.load_mod_view(report.id).await? {
+            line.push_str(&format!(" ([report]({}))", view.url(guild.id)));
+        }
+
+        if let Some(url) = report.url() {
+            line.push_str(&format!(" ([message]({}))", url));
+        }
+
+        recent_lines.push(line);
+    }
+
+    if recent_lines.is_empty() {
+        recent_lines.push("*This user has never been reported.*".to_owned());
+    }
+
+    let protected = match services::is_protected(ctx, db, guild.id, user_id).await? {
+        true => "🛡️ Yes",
+        false => "No",
+    };
+
+    msg.channel_id
+        .send_message(ctx, |m| {
+            m.embed(|e| {
+                e.author(|a| {
+                    a.icon_url(user.face())
+                        .name(format!("{} ({})", user.tag(), user.id))
+                })
+                .field("Reports", summary.total, true)
+                .field("Reporters", summary.reporters, true)
+                .field("Protected", protected, true)
+                .field("✅ Accepted", summary.accepted, true)
+                .field("❌ Denied", summary.denied, true)
+                .field("🔎 Open", summary.open(), true)
+                .field("Recent Reports", recent_lines.join("\n"), false)
+            })
+        })
+        .await?;
+
+    Ok(())
+}
@@ -3,7 +3,6 @@ use std::collections::HashMap;
 use serenity::{model::id::GuildId, prelude::RwLock};
 
 use super::models::ServerConfiguration;
-use super::Database;
 
 mod get_server_config;
 mod get_server_prefix;
@@ -28,10 +27,3 @@ impl Cache {
         lock.remove(server);
     }
 }
-
-// helpers
-impl Database {
-    pub async fn has_server_config(&self, guild_id: &GuildId) -> Result<bool, sqlx::Error> {
-        self.get_server_prefix(guild_id).await.map(|p| p.is_some())
-    }
-}
@@ -1,7 +1,9 @@
 use serenity::{
-    model::{channel::ReactionType, id::*},
+    model::{channel::ReactionType, id::*, Permissions},
     utils::Colour,
 };
+use sqlx::types::chrono::{DateTime, Utc};
+use std::{convert::TryFrom, time::Duration};
 use thiserror::Error;
 
 #[derive(Clone)]
@@ -10,19 +12,125 @@ pub struct ServerConfiguration {
     pub emoji_custom: Option<u64>,
     pub prefix: Option<String>,
     pub reports_channel: u64,
+    pub attachment_max_size: Option<u64>,
+    pub attachment_quota: Option<u64>,
+    pub mod_view_reactions: bool,
+    /// The percentage of a reporter's resolved reports that must have been
+    /// accepted for them to be in good standing.
+    pub reputation_threshold: Option<u8>,
+    pub reputation_requires_reason: bool,
+    /// How many reports each user may make.
+    pub user_rate_limit: RateLimit,
+    /// How many reports may be made in the whole server.
+    pub guild_rate_limit: RateLimit,
+    /// How many reports against the same user within `burst_window` start a
+    /// burst, which collapses further reports into a single mod view. Zero
+    /// disables bursts.
+    pub burst_threshold: u32,
+    pub burst_window: Duration,
+    /// How long reports against the same user are grouped into a case for.
+    /// Reports against the same message are always grouped.
+    pub case_window: Option<Duration>,
+    /// How many members must flag a message before its reports are sent to
+    /// the moderators.
+    pub report_threshold: u32,
+    /// How many members must flag a message before it's deleted.
+    pub auto_hide_threshold: Option<u32>,
+    /// The moderation actions moderators may take, as a bit set of
+    /// `ModerationActionKind`s.
+    pub moderation_actions: u8,
+    /// Whether reported users are warned in their DMs when a report against
+    /// them is accepted.
+    pub warn_reported_users: bool,
+    /// The template for warnings, or `None` for the built-in one.
+    pub warning_template: Option<String>,
+    /// The categories reports can be made in, ordered by name.
+    pub categories: Vec<ReportCategoryModel>,
+    /// The role pinged when a report is sent to the moderators.
+    pub mod_role_id: Option<RoleId>,
+    /// The role pinged when a report has been left alone for too long.
+    pub admin_role_id: Option<RoleId>,
+    /// How long a report may stay unhandled before the moderators are
+    /// reminded about it.
+    pub unhandled_sla: Option<Duration>,
+    /// How long a report may be under review before its handler is reminded
+    /// about it.
+    pub reviewing_sla: Option<Duration>,
+    /// Whether each mod view starts a thread to discuss the report in.
+    pub mod_view_threads: bool,
+    /// Whether moderators are hidden from reporters when messaging them.
+    pub relay_anonymous: bool,
 }
 
 impl ServerConfiguration {
+    /// Checks if the emoji is the server's report emoji, or the report emoji
+    /// of one of its categories.
     pub fn matches_emoji(&self, emoji: &ReactionType) -> bool {
-        match (self.emoji_builtin.as_ref(), self.emoji_custom, emoji) {
+        let is_server_emoji = match (self.emoji_builtin.as_ref(), self.emoji_custom, emoji) {
             (_, Some(custom), ReactionType::Custom { id, .. }) => custom == id.0,
             (Some(builtin), _, ReactionType::Unicode(unicode)) => builtin == unicode,
             (None, None, ReactionType::Unicode(unicode)) => unicode == "🚩",
             _ => false,
+        };
+
+        is_server_emoji || self.category_for_emoji(emoji).is_some()
+    }
+
+    /// Finds the category a report emoji makes reports in. Reports made with
+    /// the server's report emoji aren't in a category.
+    pub fn category_for_emoji(&self, emoji: &ReactionType) -> Option<&ReportCategoryModel> {
+        self.categories.iter().find(|c| c.matches_emoji(emoji))
+    }
+
+    pub fn category(&self, category_id: u64) -> Option<&ReportCategoryModel> {
+        self.categories.iter().find(|c| c.id == category_id)
+    }
+
+    /// Where reports in a category are sent, falling back to the reports
+    /// channel for uncategorized reports and categories without a channel.
+    pub fn reports_channel_for(&self, category_id: Option<u64>) -> ChannelId {
+        (category_id)
+            .and_then(|id| self.category(id))
+            .and_then(|c| c.channel_id)
+            .unwrap_or(ChannelId(self.reports_channel))
+    }
+
+    /// Returns how many bytes of an attachment may be archived, given the
+    /// amount of bytes the server has already archived. `None` means that the
+    /// attachment should only have its URL archived.
+    pub fn attachment_allowance(&self, used: u64) -> Option<u64> {
+        let max_size = self.attachment_max_size?;
+        let quota = self.attachment_quota?;
+        Some(max_size.min(quota.saturating_sub(used)))
+    }
+
+    pub fn allows_action(&self, kind: ModerationActionKind) -> bool {
+        self.moderation_actions & kind.bit() != 0
+    }
+
+    /// Checks if a reporter's reputation is below the server's threshold.
+    pub fn is_below_reputation_threshold(&self, reputation: &ReporterReputationModel) -> bool {
+        match (self.reputation_threshold, reputation.score()) {
+            (Some(threshold), Some(score)) => score < threshold,
+            _ => false,
         }
     }
 }
 
+/// Allows `count` actions every `per`, with the allowance refilling gradually.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    pub count: u32,
+    pub per: Duration,
+}
+
+impl RateLimit {
+    /// A count of zero means there is no limit.
+    pub fn is_enabled(&self) -> bool {
+        self.count > 0 && !self.per.is_zero()
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum ChannelLookupError {
     #[error("Report has no channel!")]
@@ -43,6 +151,13 @@ pub struct ReportModel {
     pub channel_id: Option<ChannelId>,
     pub message_id: Option<MessageId>,
     pub reason: Option<String>,
+    /// When the report was made. Reports made before this was tracked don't
+    /// have any timestamps.
+    pub created_at: Option<DateTime<Utc>>,
+    pub updated_at: Option<DateTime<Utc>>,
+    /// When the report was first accepted or denied.
+    pub resolved_at: Option<DateTime<Utc>>,
+    pub category_id: Option<u64>,
 }
 
 impl ReportModel {
@@ -53,6 +168,11 @@ impl ReportModel {
         })
     }
 
+    /// How long it took for the report to be accepted or denied.
+    pub fn response_time(&self) -> Option<Duration> {
+        (self.resolved_at? - self.created_at?).to_std().ok()
+    }
+
     pub async fn channel_name(
         &self,
         ctx: &serenity::client::Context,
@@ -81,6 +201,51 @@ impl ReportModel {
     }
 }
 
+/// What to search reports by. Every search is limited to a single server.
+#[derive(Debug, Clone)]
+pub enum ReportFilter {
+    All,
+    Status(ReportStatus),
+    ReportedUser(UserId),
+    Accuser(UserId),
+    Handler(UserId),
+    Channel(ChannelId),
+    /// Reports made within a range of time. The start is inclusive, and the
+    /// end is exclusive.
+    Created(DateTime<Utc>, DateTime<Utc>),
+}
+
+/// A lightweight view of an archived message, which doesn't load the (possibly
+/// large) attachments.
+#[derive(Debug, Clone)]
+pub struct ArchivedMessagePreviewModel {
+    pub id: u64,
+    pub content: String,
+    pub attachment_count: u64,
+    pub archived: Option<DateTime<Utc>>,
+}
+
+/// Every revision of a message that was archived, along with when the message
+/// was deleted (if it was).
+#[derive(Debug, Clone)]
+pub struct MessageHistoryModel {
+    pub revisions: Vec<ArchivedMessagePreviewModel>,
+    pub deleted: Option<DateTime<Utc>>,
+}
+
+impl MessageHistoryModel {
+    pub fn edits(&self) -> usize {
+        self.revisions.len().saturating_sub(1)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchivedAttachmentModel {
+    pub idx: u64,
+    pub url: String,
+    pub attachment: Option<Vec<u8>>,
+}
+
 #[derive(Debug, Clone)]
 pub enum ViewModel {
     User(UserViewModel),
@@ -99,8 +264,306 @@ pub struct ModViewModel {
     pub report_id: u64,
     pub channel_id: ChannelId,
     pub message_id: MessageId,
+    /// The ID of the archived message shown in the preview, or zero if there
+    /// is no message to preview.
     pub preview_archive_id: u64,
     pub handler: Option<UserId>,
+    /// The thread the report is discussed in, if it has one.
+    pub thread_id: Option<ChannelId>,
+}
+
+impl ModViewModel {
+    /// Checks if the mod view is the starter message of a forum post, rather
+    /// than a message in a text channel.
+    pub fn is_forum_post(&self) -> bool {
+        self.thread_id == Some(self.channel_id)
+    }
+
+    pub fn url(&self, guild_id: GuildId) -> String {
+        format!(
+            "https://discord.com/channels/{}/{}/{}",
+            guild_id, self.channel_id, self.message_id
+        )
+    }
+}
+
+/// A message a reporter gave as evidence when filing a report through a
+/// ticket, as it was archived.
+#[derive(Debug, Clone)]
+pub struct ReportEvidenceModel {
+    pub report_id: u64,
+    pub idx: u64,
+    /// Where the message is. For screenshots, this is the reporter's DMs.
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    /// Whether the reporter linked to the message, rather than sending it to
+    /// the bot.
+    pub is_linked: bool,
+    pub content: String,
+    pub attachment_urls: Vec<String>,
+}
+
+/// A message the bot relayed between the moderators and a reporter.
+#[derive(Debug, Clone)]
+pub struct RelayMessageModel {
+    pub report_id: u64,
+    pub author: UserId,
+    /// Whether the message was sent to the reporter, rather than from them.
+    pub to_reporter: bool,
+}
+
+/// Reports against the same user which arrived in quick succession, and are
+/// shown together in a single mod view.
+#[derive(Debug, Clone)]
+pub struct ReportBurstModel {
+    pub id: u64,
+    pub guild_id: GuildId,
+    pub reported_user_id: UserId,
+    /// The message the burst is shown in, if it has been sent.
+    pub channel_id: Option<ChannelId>,
+    pub message_id: Option<MessageId>,
+    pub created_at: DateTime<Utc>,
+    /// When the last report was added to the burst.
+    pub updated_at: DateTime<Utc>,
+    /// The thread the burst is discussed in, if it has one.
+    pub thread_id: Option<ChannelId>,
+}
+
+/// Reports against the same message, or against the same user within a window,
+/// which are handled together on the mod view of the report that started it.
+#[derive(Debug, Clone)]
+pub struct ReportCaseModel {
+    pub id: u64,
+    pub guild_id: GuildId,
+    pub reported_user_id: UserId,
+    /// The message the case is about, or `None` if it groups reports against
+    /// the user.
+    pub message_id: Option<MessageId>,
+    pub lead_report_id: u64,
+    pub created_at: DateTime<Utc>,
+}
+
+/// Something done to a reported user when their report is accepted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModerationAction {
+    DeleteMessage,
+    Timeout(Duration),
+    Kick,
+    Ban,
+}
+
+impl ModerationAction {
+    pub fn kind(&self) -> ModerationActionKind {
+        match self {
+            ModerationAction::DeleteMessage => ModerationActionKind::DeleteMessage,
+            ModerationAction::Timeout(_) => ModerationActionKind::Timeout,
+            ModerationAction::Kick => ModerationActionKind::Kick,
+            ModerationAction::Ban => ModerationActionKind::Ban,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModerationActionKind {
+    DeleteMessage,
+    Timeout,
+    Kick,
+    Ban,
+}
+
+impl ModerationActionKind {
+    pub const ALL: [ModerationActionKind; 4] = [
+        ModerationActionKind::DeleteMessage,
+        ModerationActionKind::Timeout,
+        ModerationActionKind::Kick,
+        ModerationActionKind::Ban,
+    ];
+
+    /// The bit representing the kind in `ServerConfiguration::moderation_actions`.
+    pub fn bit(self) -> u8 {
+        1 << Into::<i64>::into(self)
+    }
+
+    /// The permission a moderator needs to take the action themselves.
+    pub fn permissions(self) -> Permissions {
+        match self {
+            ModerationActionKind::DeleteMessage => Permissions::MANAGE_MESSAGES,
+            ModerationActionKind::Timeout => Permissions::MODERATE_MEMBERS,
+            ModerationActionKind::Kick => Permissions::KICK_MEMBERS,
+            ModerationActionKind::Ban => Permissions::BAN_MEMBERS,
+        }
+    }
+
+    /// The name used for the kind in commands.
+    pub fn name(self) -> &'static str {
+        match self {
+            ModerationActionKind::DeleteMessage => "delete",
+            ModerationActionKind::Timeout => "timeout",
+            ModerationActionKind::Kick => "kick",
+            ModerationActionKind::Ban => "ban",
+        }
+    }
+}
+
+/// Fails with the unknown value, so a bad row can be skipped rather than
+/// taking the bot down.
+impl TryFrom<i64> for ModerationActionKind {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::DeleteMessage,
+            1 => Self::Timeout,
+            2 => Self::Kick,
+            3 => Self::Ban,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl From<ModerationActionKind> for i64 {
+    fn from(val: ModerationActionKind) -> Self {
+        match val {
+            ModerationActionKind::DeleteMessage => 0,
+            ModerationActionKind::Timeout => 1,
+            ModerationActionKind::Kick => 2,
+            ModerationActionKind::Ban => 3,
+        }
+    }
+}
+
+/// A moderation action a moderator took when accepting a report.
+#[derive(Debug, Clone)]
+pub struct ReportActionModel {
+    pub id: u64,
+    pub report_id: u64,
+    pub moderator: UserId,
+    pub action: ModerationAction,
+    pub created: DateTime<Utc>,
+}
+
+/// A kind of report, like spam or harassment, which is routed to its own
+/// channel.
+#[derive(Debug, Clone)]
+pub struct ReportCategoryModel {
+    pub id: u64,
+    pub guild_id: GuildId,
+    pub name: String,
+    pub emoji_builtin: Option<String>,
+    pub emoji_custom: Option<u64>,
+    pub channel_id: Option<ChannelId>,
+    pub ping_role_id: Option<RoleId>,
+}
+
+impl ReportCategoryModel {
+    pub fn matches_emoji(&self, emoji: &ReactionType) -> bool {
+        match (self.emoji_builtin.as_ref(), self.emoji_custom, emoji) {
+            (_, Some(custom), ReactionType::Custom { id, .. }) => custom == id.0,
+            (Some(builtin), _, ReactionType::Unicode(unicode)) => builtin == unicode,
+            _ => false,
+        }
+    }
+
+    /// Shows the category's emoji, if it has one. Custom emojis are shown by
+    /// their ID, since their name isn't stored.
+    pub fn display_emoji(&self) -> Option<String> {
+        match (self.emoji_builtin.as_ref(), self.emoji_custom) {
+            (_, Some(custom)) => Some(format!("<:emoji:{}>", custom)),
+            (Some(builtin), _) => Some(builtin.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// An open report with a mod view, which moderators may need reminding
+/// about.
+#[derive(Debug, Clone)]
+pub struct EscalationCandidateModel {
+    pub report_id: u64,
+    pub status: ReportStatus,
+    pub channel_id: ChannelId,
+    pub message_id: MessageId,
+    pub handler: Option<UserId>,
+    pub thread_id: Option<ChannelId>,
+    /// When the report entered its current status.
+    pub status_since: DateTime<Utc>,
+    /// How many reminders have been sent since then, and when the last one
+    /// was sent.
+    pub level: u32,
+    pub alerted_at: Option<DateTime<Utc>>,
+}
+
+/// What a reported user is told when a report against them is accepted.
+#[derive(Debug, Clone)]
+pub struct ReportWarningModel {
+    pub report_id: u64,
+    pub moderator: UserId,
+    pub rule: Option<String>,
+    pub note: Option<String>,
+    pub sent: Option<DateTime<Utc>>,
+}
+
+/// A user that can't be reported in a server.
+#[derive(Debug, Clone)]
+pub struct ProtectedUserModel {
+    pub user_id: UserId,
+    /// Who protected the user and when, if it was recorded.
+    pub protected_by: Option<UserId>,
+    pub protected_at: Option<DateTime<Utc>>,
+}
+
+/// A user that may not make reports in a server.
+#[derive(Debug, Clone)]
+pub struct ReportBanModel {
+    pub user_id: UserId,
+    pub banned_by: UserId,
+    pub reason: Option<String>,
+    pub created: DateTime<Utc>,
+    /// When the ban ends, or `None` if it lasts until it's lifted.
+    pub expires: Option<DateTime<Utc>>,
+}
+
+/// How many reports a reporter needs to have had accepted or denied before
+/// their reputation is judged.
+pub const MIN_RESOLVED_REPORTS: u64 = 3;
+
+/// How many of a user's reports in a server were accepted or denied.
+#[derive(Debug, Clone)]
+pub struct ReporterReputationModel {
+    pub accepted: u64,
+    pub denied: u64,
+}
+
+impl ReporterReputationModel {
+    pub fn resolved(&self) -> u64 {
+        self.accepted + self.denied
+    }
+
+    /// The percentage of resolved reports that were accepted, or `None` if
+    /// too few reports were resolved to tell.
+    pub fn score(&self) -> Option<u8> {
+        match self.resolved() {
+            n if n < MIN_RESOLVED_REPORTS => None,
+            n => Some((self.accepted * 100 / n) as u8),
+        }
+    }
+}
+
+/// How often a user has been reported in a server.
+#[derive(Debug, Clone)]
+pub struct ReportedUserSummaryModel {
+    pub total: u64,
+    pub accepted: u64,
+    pub denied: u64,
+    /// How many different users have reported the user.
+    pub reporters: u64,
+}
+
+impl ReportedUserSummaryModel {
+    /// How many reports against the user are still being handled.
+    pub fn open(&self) -> u64 {
+        self.total - self.accepted - self.denied
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -112,6 +575,11 @@ pub enum ReportStatus {
 }
 
 impl ReportStatus {
+    /// Whether the report is still waiting on a moderator.
+    pub fn is_open(self) -> bool {
+        matches!(self, ReportStatus::Unhandled | ReportStatus::Reviewing)
+    }
+
     pub fn into_human_status(self) -> &'static str {
         match self {
             ReportStatus::Unhandled => "😴 Unhandled",
@@ -153,3 +621,90 @@ impl From<ReportStatus> for i64 {
         }
     }
 }
+
+/// A private note a moderator attached to a report.
+#[derive(Debug, Clone)]
+pub struct ReportNoteModel {
+    pub id: u64,
+    pub report_id: u64,
+    pub author: UserId,
+    pub content: String,
+    pub created: DateTime<Utc>,
+}
+
+/// Something that happened to a report, as recorded in its audit log.
+#[derive(Debug, Clone)]
+pub struct ReportEventModel {
+    pub id: u64,
+    pub report_id: u64,
+    pub actor: Option<UserId>,
+    pub kind: ReportEventKind,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub created: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportEventKind {
+    /// The report was made. The new value is the reason, if one was given.
+    Created,
+    ReasonChanged,
+    /// Both values are the integer representation of a `ReportStatus`.
+    StatusChanged,
+    /// Both values are the user ID of the moderator handling the report.
+    HandlerChanged,
+    /// The new value is the ID of the newly archived revision.
+    MessageEdited,
+    MessageDeleted,
+    /// The new value is the content of the note.
+    NoteAdded,
+    /// The new value describes the moderation action that was taken.
+    ActionTaken,
+    /// The new value is the warning the reported user was sent.
+    WarningSent,
+    /// A moderator messaged the reporter. The new value is the message.
+    RelayedToReporter,
+    /// The reporter messaged the moderators. The new value is the message.
+    RelayedFromReporter,
+}
+
+/// Fails with the unknown value, so a bad row can be skipped rather than
+/// taking the bot down.
+impl TryFrom<i64> for ReportEventKind {
+    type Error = i64;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Created,
+            1 => Self::ReasonChanged,
+            2 => Self::StatusChanged,
+            3 => Self::HandlerChanged,
+            4 => Self::MessageEdited,
+            5 => Self::MessageDeleted,
+            6 => Self::NoteAdded,
+            7 => Self::ActionTaken,
+            8 => Self::WarningSent,
+            9 => Self::RelayedToReporter,
+            10 => Self::RelayedFromReporter,
+            _ => return Err(value),
+        })
+    }
+}
+
+impl From<ReportEventKind> for i64 {
+    fn from(val: ReportEventKind) -> Self {
+        match val {
+            ReportEventKind::Created => 0,
+            ReportEventKind::ReasonChanged => 1,
+            ReportEventKind::StatusChanged => 2,
+            ReportEventKind::HandlerChanged => 3,
+            ReportEventKind::MessageEdited => 4,
+            ReportEventKind::MessageDeleted => 5,
+            ReportEventKind::NoteAdded => 6,
+            ReportEventKind::ActionTaken => 7,
+            ReportEventKind::WarningSent => 8,
+            ReportEventKind::RelayedToReporter => 9,
+            ReportEventKind::RelayedFromReporter => 10,
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use serenity::model::id::UserId;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{
+    models::{ModerationAction, ReportEventKind},
+    Database,
+};
+
+type ReportId = u64;
+
+impl Database {
+    /// Records a moderation action taken against a reported user, along with
+    /// a description of it for the report's history.
+    pub async fn add_report_action(
+        &self,
+        report_id: ReportId,
+        moderator: UserId,
+        action: ModerationAction,
+        description: &str,
+    ) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::add_report_action_in_transaction(
+            &mut connection,
+            report_id,
+            moderator,
+            action,
+            description,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn add_report_action_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        moderator: UserId,
+        action: ModerationAction,
+        description: &str,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_mid = moderator.0 as i64;
+        let db_k = Into::<i64>::into(action.kind());
+        let db_d = match action {
+            ModerationAction::Timeout(duration) => Some(duration.as_secs() as i64),
+            _ => None,
+        };
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_actions (report_id, moderator_user_id, kind, duration_seconds, created)
+VALUES (?, ?, ?, ?, DATETIME("now"));
+            "#,
+            db_rid,
+            db_mid,
+            db_k,
+            db_d
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Database::record_report_event(
+            connection,
+            report_id,
+            Some(moderator),
+            ReportEventKind::ActionTaken,
+            None,
+            Some(description),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
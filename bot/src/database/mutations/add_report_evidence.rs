@@ -0,0 +1,71 @@
+use serenity::model::channel::Message;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ArchivedAttachmentModel, Database};
+
+type ReportId = u64;
+
+impl Database {
+    /// Archives a message as evidence for a report, after any evidence it
+    /// already has.
+    pub async fn add_report_evidence(
+        &self,
+        report_id: ReportId,
+        message: &Message,
+        attachments: &[ArchivedAttachmentModel],
+        is_linked: bool,
+    ) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = self
+            .add_report_evidence_in_transaction(
+                &mut connection,
+                report_id,
+                message,
+                attachments,
+                is_linked,
+            )
+            .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn add_report_evidence_in_transaction(
+        &self,
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        message: &Message,
+        attachments: &[ArchivedAttachmentModel],
+        is_linked: bool,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_cid = message.channel_id.0 as i64;
+        let db_mid = message.id.0 as i64;
+
+        let archive_id = self
+            .archive_message_in_transaction(connection, message, attachments)
+            .await?
+            .message_id() as i64;
+
+        sqlx::query!(
+            "
+INSERT INTO report_evidence (report_id, idx, channel_id, message_id, archive_id, is_linked)
+VALUES (?, (SELECT COUNT(*) FROM report_evidence WHERE report_id = ?), ?, ?, ?, ?);
+            ",
+            db_rid,
+            db_rid,
+            db_cid,
+            db_mid,
+            archive_id,
+            is_linked
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use serenity::model::id::UserId;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportEventKind, Database};
+
+type ReportId = u64;
+
+impl Database {
+    pub async fn add_report_note(
+        &self,
+        report_id: ReportId,
+        author: UserId,
+        content: &str,
+    ) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result =
+            Database::add_report_note_in_transaction(&mut connection, report_id, author, content)
+                .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn add_report_note_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        author: UserId,
+        content: &str,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_aid = author.0 as i64;
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_notes (report_id, author_user_id, content, created)
+VALUES (?, ?, ?, DATETIME("now"));
+            "#,
+            db_rid,
+            db_aid,
+            content
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Database::record_report_event(
+            connection,
+            report_id,
+            Some(author),
+            ReportEventKind::NoteAdded,
+            None,
+            Some(content),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
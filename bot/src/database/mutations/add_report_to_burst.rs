@@ -0,0 +1,218 @@
+use std::time::Duration;
+
+use serenity::model::id::{ChannelId, GuildId, MessageId, UserId};
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportStatus, Database};
+
+type ReportId = u64;
+type BurstId = u64;
+
+impl Database {
+    /// Adds a new report to the burst of reports against the reported user,
+    /// starting one if they've been reported `threshold` times within the
+    /// window. A new burst takes in the open reports from the window too, so
+    /// the whole burst is collapsed. Returns the burst the report was added
+    /// to, if any.
+    pub async fn add_report_to_burst(
+        &self,
+        guild_id: GuildId,
+        reported_user_id: UserId,
+        report_id: ReportId,
+        threshold: u32,
+        window: Duration,
+    ) -> Result<Option<BurstId>, sqlx::Error> {
+        if threshold == 0 {
+            return Ok(None);
+        }
+
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::add_report_to_burst_in_transaction(
+            &mut connection,
+            guild_id,
+            reported_user_id,
+            report_id,
+            threshold,
+            window,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn add_report_to_burst_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        guild_id: GuildId,
+        reported_user_id: UserId,
+        report_id: ReportId,
+        threshold: u32,
+        window: Duration,
+    ) -> Result<Option<BurstId>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = reported_user_id.0 as i64;
+        let db_rid = report_id as i64;
+        let db_w = format!("-{} seconds", window.as_secs());
+
+        let burst = sqlx::query!(
+            r#"
+SELECT id FROM report_bursts
+WHERE guild_id = ?
+  AND reported_user_id = ?
+  AND updated_at >= DATETIME("now", ?)
+ORDER BY id DESC
+LIMIT 1;
+            "#,
+            db_gid,
+            db_uid,
+            db_w
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        let burst_id = match burst {
+            Some(burst) => burst.id,
+            None => {
+                let recent = sqlx::query!(
+                    r#"
+SELECT COUNT(*) AS "count!: i64" FROM reports
+WHERE guild_id = ?
+  AND reported_user_id = ?
+  AND created_at >= DATETIME("now", ?)
+  AND id NOT IN (SELECT report_id FROM pending_reports);
+                    "#,
+                    db_gid,
+                    db_uid,
+                    db_w
+                )
+                .fetch_one(&mut *connection)
+                .await?;
+
+                if recent.count < threshold as i64 {
+                    return Ok(None);
+                }
+
+                let burst_id = sqlx::query!(
+                    r#"
+INSERT INTO report_bursts (guild_id, reported_user_id, created_at, updated_at)
+VALUES (?, ?, DATETIME("now"), DATETIME("now"));
+                    "#,
+                    db_gid,
+                    db_uid
+                )
+                .execute(&mut *connection)
+                .await?
+                .last_insert_rowid();
+
+                Database::add_window_to_burst(connection, burst_id, db_gid, db_uid, db_rid, &db_w)
+                    .await?;
+
+                burst_id
+            }
+        };
+
+        sqlx::query!(
+            "
+INSERT INTO report_burst_reports (report_id, burst_id)
+VALUES (?, ?);
+            ",
+            db_rid,
+            burst_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        sqlx::query!(
+            r#"
+UPDATE report_bursts
+SET updated_at = DATETIME("now")
+WHERE id = ?;
+            "#,
+            burst_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(Some(burst_id as u64))
+    }
+
+    /// Adds the open reports against the user from within the window to a new
+    /// burst. Reports grouped into a case join through the report leading it.
+    async fn add_window_to_burst(
+        connection: &mut PoolConnection<Sqlite>,
+        burst_id: i64,
+        db_gid: i64,
+        db_uid: i64,
+        db_rid: i64,
+        db_w: &str,
+    ) -> Result<(), sqlx::Error> {
+        let db_unhandled = Into::<i64>::into(ReportStatus::Unhandled);
+        let db_reviewing = Into::<i64>::into(ReportStatus::Reviewing);
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_burst_reports (report_id, burst_id)
+SELECT id, ? FROM reports
+WHERE guild_id = ?
+  AND reported_user_id = ?
+  AND id != ?
+  AND status IN (?, ?)
+  AND created_at >= DATETIME("now", ?)
+  AND id NOT IN (SELECT report_id FROM pending_reports)
+  AND id NOT IN (SELECT report_id FROM report_burst_reports)
+  AND (
+       id NOT IN (SELECT report_id FROM report_case_reports)
+    OR id IN (SELECT lead_report_id FROM report_cases)
+  );
+            "#,
+            burst_id,
+            db_gid,
+            db_uid,
+            db_rid,
+            db_unhandled,
+            db_reviewing,
+            db_w
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records the message a burst is shown in, and the thread it's discussed
+    /// in.
+    pub async fn save_burst_view(
+        &self,
+        burst_id: BurstId,
+        channel_id: ChannelId,
+        message_id: MessageId,
+        thread_id: Option<ChannelId>,
+    ) -> Result<(), sqlx::Error> {
+        let db_bid = burst_id as i64;
+        let db_cid = channel_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+        let db_tid = thread_id.map(|t| t.0 as i64);
+
+        sqlx::query!(
+            "
+UPDATE report_bursts
+SET channel_id = ?,
+    message_id = ?,
+    thread_id = ?
+WHERE id = ?
+            ",
+            db_cid,
+            db_mid,
+            db_tid,
+            db_bid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+}
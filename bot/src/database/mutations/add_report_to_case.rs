@@ -0,0 +1,230 @@
+use std::time::Duration;
+
+use serenity::model::id::{GuildId, MessageId, UserId};
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportStatus, Database};
+
+type ReportId = u64;
+type CaseId = u64;
+
+/// Everything needed to find the case a new report belongs in.
+struct CaseLookup {
+    db_gid: i64,
+    db_uid: i64,
+    db_rid: i64,
+    db_unhandled: i64,
+    db_reviewing: i64,
+}
+
+impl Database {
+    /// Groups a new report with the open reports against the same message, or
+    /// against the same user within `user_window`. The report that started the
+    /// case leads it, and its mod view shows the whole case. Returns the case
+    /// the report was added to, if any.
+    pub async fn add_report_to_case(
+        &self,
+        guild_id: GuildId,
+        reported_user_id: UserId,
+        message_id: Option<MessageId>,
+        report_id: ReportId,
+        user_window: Option<Duration>,
+    ) -> Result<Option<CaseId>, sqlx::Error> {
+        let lookup = CaseLookup {
+            db_gid: guild_id.0 as i64,
+            db_uid: reported_user_id.0 as i64,
+            db_rid: report_id as i64,
+            db_unhandled: ReportStatus::Unhandled.into(),
+            db_reviewing: ReportStatus::Reviewing.into(),
+        };
+
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let mut result = Ok(None);
+
+        if let Some(message_id) = message_id {
+            result =
+                Database::add_report_to_message_case(&mut connection, &lookup, message_id).await;
+        }
+
+        if let (Ok(None), Some(window)) = (&result, user_window) {
+            result = Database::add_report_to_user_case(&mut connection, &lookup, window).await;
+        }
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn add_report_to_message_case(
+        connection: &mut PoolConnection<Sqlite>,
+        lookup: &CaseLookup,
+        message_id: MessageId,
+    ) -> Result<Option<CaseId>, sqlx::Error> {
+        let db_mid = message_id.0 as i64;
+
+        let case = sqlx::query!(
+            "
+SELECT id FROM report_cases
+WHERE guild_id = ?
+  AND message_id = ?
+  AND lead_report_id IN (SELECT id FROM reports WHERE status IN (?, ?))
+ORDER BY id DESC
+LIMIT 1;
+            ",
+            lookup.db_gid,
+            db_mid,
+            lookup.db_unhandled,
+            lookup.db_reviewing
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        if let Some(case) = case {
+            Database::join_case(connection, case.id, lookup.db_rid).await?;
+            return Ok(Some(case.id as u64));
+        }
+
+        let lead = sqlx::query!(
+            "
+SELECT id FROM reports
+WHERE guild_id = ?
+  AND message_id = ?
+  AND id != ?
+  AND status IN (?, ?)
+  AND id NOT IN (SELECT report_id FROM report_case_reports)
+  AND id NOT IN (SELECT report_id FROM pending_reports)
+ORDER BY id ASC
+LIMIT 1;
+            ",
+            lookup.db_gid,
+            db_mid,
+            lookup.db_rid,
+            lookup.db_unhandled,
+            lookup.db_reviewing
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        match lead {
+            Some(lead) => {
+                let case_id =
+                    Database::start_case(connection, lookup, Some(db_mid), lead.id).await?;
+                Ok(Some(case_id))
+            }
+            None => Ok(None),
+        }
+    }
+
+    async fn add_report_to_user_case(
+        connection: &mut PoolConnection<Sqlite>,
+        lookup: &CaseLookup,
+        window: Duration,
+    ) -> Result<Option<CaseId>, sqlx::Error> {
+        let db_w = format!("-{} seconds", window.as_secs());
+
+        let case = sqlx::query!(
+            r#"
+SELECT id FROM report_cases
+WHERE guild_id = ?
+  AND reported_user_id = ?
+  AND message_id IS NULL
+  AND created_at >= DATETIME("now", ?)
+  AND lead_report_id IN (SELECT id FROM reports WHERE status IN (?, ?))
+ORDER BY id DESC
+LIMIT 1;
+            "#,
+            lookup.db_gid,
+            lookup.db_uid,
+            db_w,
+            lookup.db_unhandled,
+            lookup.db_reviewing
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        if let Some(case) = case {
+            Database::join_case(connection, case.id, lookup.db_rid).await?;
+            return Ok(Some(case.id as u64));
+        }
+
+        let lead = sqlx::query!(
+            r#"
+SELECT id FROM reports
+WHERE guild_id = ?
+  AND reported_user_id = ?
+  AND id != ?
+  AND status IN (?, ?)
+  AND created_at >= DATETIME("now", ?)
+  AND id NOT IN (SELECT report_id FROM report_case_reports)
+  AND id NOT IN (SELECT report_id FROM pending_reports)
+ORDER BY id ASC
+LIMIT 1;
+            "#,
+            lookup.db_gid,
+            lookup.db_uid,
+            lookup.db_rid,
+            lookup.db_unhandled,
+            lookup.db_reviewing,
+            db_w
+        )
+        .fetch_optional(&mut *connection)
+        .await?;
+
+        match lead {
+            Some(lead) => {
+                let case_id = Database::start_case(connection, lookup, None, lead.id).await?;
+                Ok(Some(case_id))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Starts a case led by an existing report, and adds the new report to it.
+    async fn start_case(
+        connection: &mut PoolConnection<Sqlite>,
+        lookup: &CaseLookup,
+        db_mid: Option<i64>,
+        lead_report_id: i64,
+    ) -> Result<CaseId, sqlx::Error> {
+        let case_id = sqlx::query!(
+            r#"
+INSERT INTO report_cases (guild_id, reported_user_id, message_id, lead_report_id, created_at)
+VALUES (?, ?, ?, ?, DATETIME("now"));
+            "#,
+            lookup.db_gid,
+            lookup.db_uid,
+            db_mid,
+            lead_report_id
+        )
+        .execute(&mut *connection)
+        .await?
+        .last_insert_rowid();
+
+        Database::join_case(connection, case_id, lead_report_id).await?;
+        Database::join_case(connection, case_id, lookup.db_rid).await?;
+
+        Ok(case_id as u64)
+    }
+
+    async fn join_case(
+        connection: &mut PoolConnection<Sqlite>,
+        case_id: i64,
+        report_id: i64,
+    ) -> Result<(), sqlx::Error> {
+        sqlx::query!(
+            "
+INSERT INTO report_case_reports (report_id, case_id)
+VALUES (?, ?);
+            ",
+            report_id,
+            case_id
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+}
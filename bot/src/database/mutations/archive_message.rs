@@ -1,9 +1,12 @@
 use serenity::model::channel::Message;
 use sqlx::{pool::PoolConnection, Sqlite};
 
-use crate::database::Database;
+use crate::database::{
+    models::{ArchivedAttachmentModel, ReportEventKind},
+    Database,
+};
 
-type ArchiveMessageId = u64;
+pub type ArchiveMessageId = u64;
 
 /// Describes the effects that happened when making the report. This is used
 /// to accurately update the view.
@@ -22,31 +25,58 @@ impl ArchiveMessageEffect {
 }
 
 impl Database {
-    // pub async fn archive_message(
-    //     &self,
-    //     message: &Message,
-    // ) -> Result<ArchiveMessageEffect, sqlx::Error> {
-    //     let mut connection = self.connection.acquire().await?;
+    pub async fn archive_message(
+        &self,
+        message: &Message,
+        attachments: &[ArchivedAttachmentModel],
+    ) -> Result<ArchiveMessageEffect, sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
 
-    //     // since we are creating reports, we want exclusive access to the DB
-    //     // while we update it
-    //     sqlx::query("BEGIN EXCLUSIVE;")
-    //         .execute(&mut connection)
-    //         .await?;
+        // since we are archiving a new revision, we want exclusive access to
+        // the DB while we update it
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
 
-    //     let effect = self
-    //         .archive_message_in_transaction(&mut connection, message)
-    //         .await?;
+        let result = self
+            .archive_edit_in_transaction(&mut connection, message, attachments)
+            .await;
 
-    //     sqlx::query("COMMIT;").execute(&mut connection).await?;
+        Database::end_transaction(&mut connection, result).await
+    }
 
-    //     Ok(effect)
-    // }
+    /// Archives a message that was edited after being reported, recording the
+    /// edit in the history of its reports. Messages are only archived outside
+    /// of making a report when this happens.
+    async fn archive_edit_in_transaction(
+        &self,
+        connection: &mut PoolConnection<Sqlite>,
+        message: &Message,
+        attachments: &[ArchivedAttachmentModel],
+    ) -> Result<ArchiveMessageEffect, sqlx::Error> {
+        let effect = self
+            .archive_message_in_transaction(connection, message, attachments)
+            .await?;
+
+        if let ArchiveMessageEffect::Archived(archive_id) = effect {
+            Database::record_message_event(
+                connection,
+                message.id.0 as i64,
+                Some(message.author.id),
+                ReportEventKind::MessageEdited,
+                Some(&archive_id.to_string()),
+            )
+            .await?;
+        }
+
+        Ok(effect)
+    }
 
     pub(super) async fn archive_message_in_transaction(
         &self,
         connection: &mut PoolConnection<Sqlite>,
         message: &Message,
+        attachments: &[ArchivedAttachmentModel],
     ) -> Result<ArchiveMessageEffect, sqlx::Error> {
         let db_msg_id = message.id.0 as i64;
 
@@ -63,36 +93,75 @@ LIMIT 1;
         .fetch_optional(&mut *connection)
         .await?;
 
-        match archived_message {
-            Some(archived_message) => {
-                let need_update = archived_message.content != message.content;
-
-                if !need_update {
-                    return Ok(ArchiveMessageEffect::None(archived_message.id as u64));
-                }
-
-                // we will need to update it at this point - fall into the case
-                // where we update it
-            }
-            None => {
-                // don't do anything, fall into the case where we archive the message
+        // with no archived copy yet, fall into the case where we archive the
+        // message
+        if let Some(archived_message) = archived_message {
+            let archived_urls = sqlx::query!(
+                "
+SELECT url FROM attachment_archive
+WHERE id = ?
+ORDER BY idx ASC;
+                    ",
+                archived_message.id
+            )
+            .fetch_all(&mut *connection)
+            .await?;
+
+            let attachments_changed = !archived_urls
+                .iter()
+                .map(|r| attachment_key(&r.url))
+                .eq(attachments.iter().map(|a| attachment_key(&a.url)));
+
+            let need_update = archived_message.content != message.content || attachments_changed;
+
+            if !need_update {
+                return Ok(ArchiveMessageEffect::None(archived_message.id as u64));
             }
-        };
+
+            // we will need to update it at this point - fall into the case
+            // where we update it
+        }
 
         let db_content = message.content.clone();
         let archived_message = sqlx::query!(
-            "
-INSERT INTO message_archive (message_id, content)
-VALUES (?, ?)
-            ",
+            r#"
+INSERT INTO message_archive (message_id, content, archived)
+VALUES (?, ?, DATETIME("now"))
+            "#,
             db_msg_id,
             db_content
         )
         .execute(&mut *connection)
         .await?;
 
-        Ok(ArchiveMessageEffect::Archived(
-            archived_message.last_insert_rowid() as u64,
-        ))
+        let archive_id = archived_message.last_insert_rowid();
+
+        for attachment in attachments {
+            let db_idx = attachment.idx as i64;
+            let db_url = attachment.url.as_str();
+            let db_a = attachment.attachment.as_deref();
+
+            sqlx::query!(
+                "
+INSERT INTO attachment_archive (id, idx, url, attachment)
+VALUES (?, ?, ?, ?)
+                ",
+                archive_id,
+                db_idx,
+                db_url,
+                db_a
+            )
+            .execute(&mut *connection)
+            .await?;
+        }
+
+        Ok(ArchiveMessageEffect::Archived(archive_id as u64))
     }
 }
+
+/// Attachment URLs are signed, so their query string changes each time the
+/// message is fetched. The path holds the attachment's ID and filename, which
+/// don't change.
+fn attachment_key(url: &str) -> &str {
+    url.split_once('?').map_or(url, |(path, _)| path)
+}
@@ -0,0 +1,21 @@
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::Database;
+
+impl Database {
+    /// Commits the transaction if everything within it succeeded, and rolls it
+    /// back otherwise so the connection isn't returned to the pool mid
+    /// transaction.
+    pub(super) async fn end_transaction<T, E: From<sqlx::Error>>(
+        connection: &mut PoolConnection<Sqlite>,
+        result: Result<T, E>,
+    ) -> Result<T, E> {
+        let end = match result {
+            Ok(_) => "COMMIT;",
+            Err(_) => "ROLLBACK;",
+        };
+        sqlx::query(end).execute(&mut *connection).await?;
+
+        result
+    }
+}
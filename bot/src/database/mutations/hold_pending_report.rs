@@ -0,0 +1,85 @@
+use serenity::model::id::{GuildId, MessageId};
+
+use super::ArchiveMessageId;
+use crate::database::Database;
+
+type ReportId = u64;
+
+impl Database {
+    /// Holds a report back from the moderators until its message has been
+    /// flagged by enough members. Reports on messages that never reach the
+    /// threshold are never sent, which is how the threshold filters out
+    /// reports that few members agree with.
+    pub async fn hold_pending_report(
+        &self,
+        report_id: ReportId,
+        guild_id: GuildId,
+        message_id: MessageId,
+        preview_archive_id: Option<ArchiveMessageId>,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_gid = guild_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+        let db_paid = preview_archive_id.map(|id| id as i64);
+
+        sqlx::query!(
+            "
+INSERT OR REPLACE INTO pending_reports (report_id, guild_id, message_id, preview_archive_id)
+VALUES (?, ?, ?, ?);
+            ",
+            db_rid,
+            db_gid,
+            db_mid,
+            db_paid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Stops holding back the reports against a message, returning them along
+    /// with the archived copy of the message to preview, oldest report first.
+    pub async fn release_pending_reports(
+        &self,
+        guild_id: GuildId,
+        message_id: MessageId,
+    ) -> Result<Vec<(ReportId, Option<ArchiveMessageId>)>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+
+        let mut transaction = self.connection.begin().await?;
+
+        let pending = sqlx::query!(
+            "
+SELECT report_id, preview_archive_id FROM pending_reports
+WHERE guild_id = ?
+  AND message_id = ?
+ORDER BY report_id ASC;
+            ",
+            db_gid,
+            db_mid
+        )
+        .fetch_all(&mut transaction)
+        .await?;
+
+        sqlx::query!(
+            "
+DELETE FROM pending_reports
+WHERE guild_id = ?
+  AND message_id = ?;
+            ",
+            db_gid,
+            db_mid
+        )
+        .execute(&mut transaction)
+        .await?;
+
+        transaction.commit().await?;
+
+        Ok(pending
+            .into_iter()
+            .map(|p| (p.report_id as u64, p.preview_archive_id.map(|id| id as u64)))
+            .collect())
+    }
+}
@@ -3,12 +3,16 @@ use sqlx::{pool::PoolConnection, Sqlite};
 
 use crate::database::{models::*, Database};
 
+use super::ArchiveMessageId;
+
 type ReportId = u64;
 
 /// Describes the effects that happened when making the report. This is used
 /// to accurately update the view.
 pub enum MakeReportEffect {
-    Created(ReportId),
+    /// A new report was created, along with the ID of the archived copy of the
+    /// reported message (if there was a reported message).
+    Created(ReportId, Option<ArchiveMessageId>),
     Updated(ReportId),
     Duplicate(ReportId),
 }
@@ -22,6 +26,7 @@ impl Database {
         user_reporting: &User,
         reported_user: &User,
         reported_message: Option<&Message>,
+        reported_attachments: &[ArchivedAttachmentModel],
         report_reason: Option<&str>,
     ) -> Result<MakeReportEffect, sqlx::Error> {
         let mut connection = self.connection.acquire().await?;
@@ -30,26 +35,20 @@ impl Database {
             .execute(&mut connection)
             .await?;
 
-        if let Some(message) = reported_message {
-            self.archive_message_in_transaction(&mut connection, message)
-                .await?
-                .message_id();
-        }
+        let reported_message = reported_message.map(|m| (m, reported_attachments));
 
-        let effect = self
+        let result = self
             .create_report(
                 &mut connection,
                 guild_id,
-                &user_reporting,
-                &reported_user,
+                user_reporting,
+                reported_user,
                 reported_message,
                 report_reason,
             )
-            .await?;
+            .await;
 
-        sqlx::query("COMMIT;").execute(&mut connection).await?;
-
-        Ok(effect)
+        Database::end_transaction(&mut connection, result).await
     }
 
     async fn create_report(
@@ -58,9 +57,20 @@ impl Database {
         guild_id: GuildId,
         user_reporting: &User,
         reported_user: &User,
-        reported_message: Option<&Message>,
+        reported_message: Option<(&Message, &[ArchivedAttachmentModel])>,
         report_reason: Option<&str>,
     ) -> Result<MakeReportEffect, sqlx::Error> {
+        let archive_id = match reported_message {
+            Some((message, attachments)) => Some(
+                self.archive_message_in_transaction(connection, message, attachments)
+                    .await?
+                    .message_id(),
+            ),
+            None => None,
+        };
+
+        let reported_message = reported_message.map(|(m, _)| m);
+
         let db_gid = guild_id.0 as i64;
         let db_mid = reported_message.map(|m| m.id.0 as i64);
         let db_aid = user_reporting.id.0 as i64;
@@ -82,10 +92,10 @@ impl Database {
 
         // create the report
         let report = sqlx::query!(
-            "
-INSERT INTO reports (accuser_user_id, reported_user_id, guild_id, status, channel_id, message_id, reason)
-VALUES (?, ?, ?, ?, ?, ?, ?);
-            ",
+            r#"
+INSERT INTO reports (accuser_user_id, reported_user_id, guild_id, status, channel_id, message_id, reason, created_at, updated_at)
+VALUES (?, ?, ?, ?, ?, ?, ?, DATETIME("now"), DATETIME("now"));
+            "#,
             db_aid,
             db_rid,
             db_gid,
@@ -97,7 +107,19 @@ VALUES (?, ?, ?, ?, ?, ?, ?);
         .execute(&mut *connection)
         .await?;
 
-        Ok(MakeReportEffect::Created(report.last_insert_rowid() as u64))
+        let report_id = report.last_insert_rowid() as u64;
+
+        Database::record_report_event(
+            connection,
+            report_id,
+            Some(user_reporting.id),
+            ReportEventKind::Created,
+            None,
+            report_reason,
+        )
+        .await?;
+
+        Ok(MakeReportEffect::Created(report_id, archive_id))
     }
 
     /// Fetches an existingh report with the same accuser id and message id, to
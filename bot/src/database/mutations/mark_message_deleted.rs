@@ -0,0 +1,51 @@
+use serenity::model::id::MessageId;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportEventKind, Database};
+
+impl Database {
+    pub async fn mark_message_deleted(&self, message_id: MessageId) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result =
+            Database::mark_message_deleted_in_transaction(&mut connection, message_id).await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn mark_message_deleted_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        message_id: MessageId,
+    ) -> Result<(), sqlx::Error> {
+        let db_mid = message_id.0 as i64;
+
+        // if we somehow hear about the deletion twice, keep the first time
+        let result = sqlx::query!(
+            r#"
+INSERT OR IGNORE INTO deleted_messages (message_id, deleted)
+VALUES (?, DATETIME("now"));
+            "#,
+            db_mid
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        if result.rows_affected() > 0 {
+            // we can't tell who deleted the message
+            Database::record_message_event(
+                connection,
+                db_mid,
+                None,
+                ReportEventKind::MessageDeleted,
+                None,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
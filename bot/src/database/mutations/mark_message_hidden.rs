@@ -0,0 +1,29 @@
+use serenity::model::id::{GuildId, MessageId};
+
+use crate::database::Database;
+
+impl Database {
+    /// Marks a message as automatically hidden. Returns `false` if it already
+    /// was, in which case it shouldn't be deleted again.
+    pub async fn mark_message_hidden(
+        &self,
+        guild_id: GuildId,
+        message_id: MessageId,
+    ) -> Result<bool, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+
+        let result = sqlx::query!(
+            r#"
+INSERT OR IGNORE INTO hidden_messages (guild_id, message_id, hidden_at)
+VALUES (?, ?, DATETIME("now"));
+            "#,
+            db_gid,
+            db_mid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
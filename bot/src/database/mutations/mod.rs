@@ -4,10 +4,26 @@ pub use make_report::*;
 mod archive_message;
 pub use archive_message::*;
 
+mod end_transaction;
+mod mark_message_deleted;
+mod mark_message_hidden;
+mod record_report_event;
+
 mod update_report;
 pub use update_report::*;
 
+mod add_report_action;
+mod add_report_evidence;
+mod add_report_note;
+mod add_report_to_burst;
+mod add_report_to_case;
+mod hold_pending_report;
+mod save_relay_message;
+mod save_report_warning;
+
 mod save_mod_view;
+mod save_report_category;
+mod save_report_escalation;
 mod save_sever_configuration;
 mod save_user_view;
 
@@ -15,4 +31,6 @@ mod delete_welcome;
 mod get_welcomed_servers;
 mod make_welcome;
 
+mod set_protected_role;
 mod set_protected_user;
+mod set_report_ban;
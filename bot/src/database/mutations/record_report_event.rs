@@ -0,0 +1,73 @@
+use serenity::model::id::UserId;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportEventKind, Database};
+
+type ReportId = u64;
+
+impl Database {
+    /// Appends an event to the audit log of a report. This should be called
+    /// within the same transaction as the mutation it describes, so that the
+    /// log never disagrees with the report.
+    pub(super) async fn record_report_event(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        actor: Option<UserId>,
+        kind: ReportEventKind,
+        old_value: Option<&str>,
+        new_value: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_aid = actor.map(|a| a.0 as i64);
+        let db_k = Into::<i64>::into(kind);
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_events (report_id, actor_user_id, kind, old_value, new_value, created)
+VALUES (?, ?, ?, ?, ?, DATETIME("now"));
+            "#,
+            db_rid,
+            db_aid,
+            db_k,
+            old_value,
+            new_value
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records an event on every report about a message.
+    pub(super) async fn record_message_event(
+        connection: &mut PoolConnection<Sqlite>,
+        message_id: i64,
+        actor: Option<UserId>,
+        kind: ReportEventKind,
+        new_value: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let reports = sqlx::query!(
+            "
+SELECT id FROM reports
+WHERE message_id = ?;
+            ",
+            message_id
+        )
+        .fetch_all(&mut *connection)
+        .await?;
+
+        for report in reports {
+            Database::record_report_event(
+                connection,
+                report.id as u64,
+                actor,
+                kind,
+                None,
+                new_value,
+            )
+            .await?;
+        }
+
+        Ok(())
+    }
+}
@@ -7,13 +7,30 @@ impl Database {
         let db_mid = view.message_id.0 as i64;
         let db_paid = view.preview_archive_id as i64;
         let db_h = view.handler.map(|x| x.0 as i64);
+        let db_tid = view.thread_id.map(|x| x.0 as i64);
 
         sqlx::query!(
             "
-INSERT OR REPLACE INTO discord_mod_view (report_id, channel_id, message_id, preview_archive_id, handler)
-VALUES (?, ?, ?, ?, ?)
+INSERT OR REPLACE INTO discord_mod_view (report_id, channel_id, message_id, preview_archive_id, handler, thread_id)
+VALUES (?, ?, ?, ?, ?, ?)
             ",
-            db_rid, db_cid, db_mid, db_paid, db_h
+            db_rid, db_cid, db_mid, db_paid, db_h, db_tid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Forgets the mod view of a report, once it's shown elsewhere.
+    pub async fn delete_mod_view(&self, report_id: u64) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        sqlx::query!(
+            "
+DELETE FROM discord_mod_view WHERE report_id = ?
+            ",
+            db_rid
         )
         .execute(&self.connection)
         .await?;
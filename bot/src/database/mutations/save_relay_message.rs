@@ -0,0 +1,86 @@
+use serenity::model::id::{ChannelId, MessageId, UserId};
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportEventKind, Database};
+
+type ReportId = u64;
+
+impl Database {
+    /// Records a message relayed between the moderators and the reporter, and
+    /// where the bot relayed it to.
+    pub async fn save_relay_message(
+        &self,
+        report_id: ReportId,
+        author: UserId,
+        to_reporter: bool,
+        content: &str,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::save_relay_message_in_transaction(
+            &mut connection,
+            report_id,
+            author,
+            to_reporter,
+            content,
+            channel_id,
+            message_id,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn save_relay_message_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        author: UserId,
+        to_reporter: bool,
+        content: &str,
+        channel_id: ChannelId,
+        message_id: MessageId,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_aid = author.0 as i64;
+        let db_cid = channel_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_relay_messages (report_id, author_user_id, to_reporter, content, channel_id, message_id, created)
+VALUES (?, ?, ?, ?, ?, ?, DATETIME("now"));
+            "#,
+            db_rid,
+            db_aid,
+            to_reporter,
+            content,
+            db_cid,
+            db_mid
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        let kind = match to_reporter {
+            true => ReportEventKind::RelayedToReporter,
+            false => ReportEventKind::RelayedFromReporter,
+        };
+
+        Database::record_report_event(
+            connection,
+            report_id,
+            Some(author),
+            kind,
+            None,
+            Some(content),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,118 @@
+use serenity::model::{
+    channel::ReactionType,
+    id::{ChannelId, GuildId, RoleId},
+};
+
+use crate::database::Database;
+
+impl Database {
+    /// Creates a report category, or replaces the category with the same
+    /// name.
+    pub async fn save_report_category(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+        emoji: Option<&ReactionType>,
+        channel_id: Option<ChannelId>,
+        ping_role_id: Option<RoleId>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_cid = channel_id.map(|c| c.0 as i64);
+        let db_rid = ping_role_id.map(|r| r.0 as i64);
+
+        let (emoji_builtin, emoji_custom) = match emoji {
+            Some(ReactionType::Custom { id, .. }) => (None, Some(id.0 as i64)),
+            Some(ReactionType::Unicode(str)) => (Some(str.as_str()), None),
+            _ => (None, None),
+        };
+
+        sqlx::query!(
+            "
+INSERT INTO report_categories (guild_id, name, emoji_builtin, emoji_custom, channel_id, ping_role_id)
+VALUES (?, ?, ?, ?, ?, ?)
+ON CONFLICT (guild_id, name) DO UPDATE
+SET emoji_builtin = excluded.emoji_builtin,
+    emoji_custom = excluded.emoji_custom,
+    channel_id = excluded.channel_id,
+    ping_role_id = excluded.ping_role_id
+            ",
+            db_gid,
+            name,
+            emoji_builtin,
+            emoji_custom,
+            db_cid,
+            db_rid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    /// Deletes a report category. Reports that were in it become
+    /// uncategorized. Returns whether the category existed.
+    pub async fn delete_report_category(
+        &self,
+        guild_id: GuildId,
+        name: &str,
+    ) -> Result<bool, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        let mut transaction = self.connection.begin().await?;
+
+        sqlx::query!(
+            "
+UPDATE reports
+SET category_id = NULL
+WHERE category_id IN (SELECT id FROM report_categories WHERE guild_id = ? AND name = ?)
+            ",
+            db_gid,
+            name
+        )
+        .execute(&mut transaction)
+        .await?;
+
+        let deleted = sqlx::query!(
+            "
+DELETE FROM report_categories
+WHERE guild_id = ? AND name = ?
+            ",
+            db_gid,
+            name
+        )
+        .execute(&mut transaction)
+        .await?
+        .rows_affected();
+
+        transaction.commit().await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(deleted > 0)
+    }
+
+    pub async fn set_report_category(
+        &self,
+        report_id: u64,
+        category_id: u64,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_cid = category_id as i64;
+
+        sqlx::query!(
+            "
+UPDATE reports
+SET category_id = ?
+WHERE id = ?
+            ",
+            db_cid,
+            db_rid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,36 @@
+use crate::database::{models::ReportStatus, Database};
+
+type ReportId = u64;
+
+impl Database {
+    /// Records that moderators were reminded about a report in its current
+    /// status.
+    pub async fn save_report_escalation(
+        &self,
+        report_id: ReportId,
+        status: ReportStatus,
+        level: u32,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_s = Into::<i64>::into(status);
+        let db_l = level as i64;
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_escalations (report_id, status, level, alerted_at)
+VALUES (?, ?, ?, DATETIME("now"))
+ON CONFLICT (report_id) DO UPDATE
+SET status = excluded.status,
+    level = excluded.level,
+    alerted_at = excluded.alerted_at;
+            "#,
+            db_rid,
+            db_s,
+            db_l
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+}
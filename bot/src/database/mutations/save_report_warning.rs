@@ -0,0 +1,99 @@
+use serenity::model::id::UserId;
+use sqlx::{pool::PoolConnection, Sqlite};
+
+use crate::database::{models::ReportEventKind, Database};
+
+type ReportId = u64;
+
+impl Database {
+    /// Sets the rule and note a reported user is warned with once the report
+    /// against them is accepted.
+    pub async fn save_report_warning(
+        &self,
+        report_id: ReportId,
+        moderator: UserId,
+        rule: Option<&str>,
+        note: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_mid = moderator.0 as i64;
+
+        sqlx::query!(
+            "
+INSERT INTO report_warnings (report_id, moderator_user_id, rule, note)
+VALUES (?, ?, ?, ?)
+ON CONFLICT (report_id) DO UPDATE
+SET moderator_user_id = excluded.moderator_user_id,
+    rule = excluded.rule,
+    note = excluded.note;
+            ",
+            db_rid,
+            db_mid,
+            rule,
+            note
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Records that a reported user was sent a warning about a report.
+    pub async fn mark_warning_sent(
+        &self,
+        report_id: ReportId,
+        moderator: UserId,
+        content: &str,
+    ) -> Result<(), sqlx::Error> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::mark_warning_sent_in_transaction(
+            &mut connection,
+            report_id,
+            moderator,
+            content,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn mark_warning_sent_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        moderator: UserId,
+        content: &str,
+    ) -> Result<(), sqlx::Error> {
+        let db_rid = report_id as i64;
+        let db_mid = moderator.0 as i64;
+
+        sqlx::query!(
+            r#"
+INSERT INTO report_warnings (report_id, moderator_user_id, sent)
+VALUES (?, ?, DATETIME("now"))
+ON CONFLICT (report_id) DO UPDATE
+SET sent = excluded.sent;
+            "#,
+            db_rid,
+            db_mid
+        )
+        .execute(&mut *connection)
+        .await?;
+
+        Database::record_report_event(
+            connection,
+            report_id,
+            Some(moderator),
+            ReportEventKind::WarningSent,
+            None,
+            Some(content),
+        )
+        .await?;
+
+        Ok(())
+    }
+}
@@ -1,9 +1,11 @@
+use std::time::Duration;
+
 use serenity::model::{
     channel::ReactionType,
-    id::{ChannelId, GuildId},
+    id::{ChannelId, GuildId, RoleId},
 };
 
-use crate::database::Database;
+use crate::database::{models::RateLimit, Database};
 
 impl Database {
     pub async fn save_server_configuration(
@@ -12,8 +14,12 @@ impl Database {
         report_emoji: ReactionType,
         reports_channel: ChannelId,
         prefix: Option<&str>,
+        report_threshold: u32,
+        auto_hide_threshold: Option<u32>,
     ) -> Result<(), sqlx::Error> {
         let db_gid = guild_id.0 as i64;
+        let db_rt = report_threshold as i64;
+        let db_aht = auto_hide_threshold.map(|n| n as i64);
 
         let reports_channel = reports_channel.0 as i64;
 
@@ -26,14 +32,23 @@ impl Database {
         let mut transaction = self.connection.begin().await?;
         sqlx::query!(
             "
-INSERT OR REPLACE INTO server_configuration (guild_id, reports_channel, emoji_builtin, emoji_custom, prefix)
-VALUES (?, ?, ?, ?, ?)
+INSERT INTO server_configuration (guild_id, reports_channel, emoji_builtin, emoji_custom, prefix, report_threshold, auto_hide_threshold)
+VALUES (?, ?, ?, ?, ?, ?, ?)
+ON CONFLICT (guild_id) DO UPDATE
+SET reports_channel = excluded.reports_channel,
+    emoji_builtin = excluded.emoji_builtin,
+    emoji_custom = excluded.emoji_custom,
+    prefix = excluded.prefix,
+    report_threshold = excluded.report_threshold,
+    auto_hide_threshold = excluded.auto_hide_threshold
             ",
             db_gid,
             reports_channel,
             emoji_builtin,
             emoji_custom,
-            prefix
+            prefix,
+            db_rt,
+            db_aht
         )
         .execute(&mut transaction)
         .await?;
@@ -43,4 +58,289 @@ VALUES (?, ?, ?, ?, ?)
 
         Ok(())
     }
+
+    pub async fn save_attachment_archival(
+        &self,
+        guild_id: GuildId,
+        max_size: Option<u64>,
+        quota: Option<u64>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_ms = max_size.map(|n| n as i64);
+        let db_q = quota.map(|n| n as i64);
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET attachment_max_size = ?,
+    attachment_quota = ?
+WHERE guild_id = ?
+            ",
+            db_ms,
+            db_q,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_mod_view_controls(
+        &self,
+        guild_id: GuildId,
+        reactions: bool,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET mod_view_reactions = ?
+WHERE guild_id = ?
+            ",
+            reactions,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_mod_view_threads(
+        &self,
+        guild_id: GuildId,
+        threads: bool,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET mod_view_threads = ?
+WHERE guild_id = ?
+            ",
+            threads,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_relay_anonymous(
+        &self,
+        guild_id: GuildId,
+        anonymous: bool,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET relay_anonymous = ?
+WHERE guild_id = ?
+            ",
+            anonymous,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_reputation_threshold(
+        &self,
+        guild_id: GuildId,
+        threshold: Option<u8>,
+        requires_reason: bool,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_t = threshold.map(|n| n as i64);
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET reputation_threshold = ?,
+    reputation_requires_reason = ?
+WHERE guild_id = ?
+            ",
+            db_t,
+            requires_reason,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_rate_limits(
+        &self,
+        guild_id: GuildId,
+        user_rate_limit: RateLimit,
+        guild_rate_limit: RateLimit,
+        burst_threshold: u32,
+        burst_window: Duration,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uc = user_rate_limit.count as i64;
+        let db_us = user_rate_limit.per.as_secs() as i64;
+        let db_gc = guild_rate_limit.count as i64;
+        let db_gs = guild_rate_limit.per.as_secs() as i64;
+        let db_bt = burst_threshold as i64;
+        let db_bw = burst_window.as_secs() as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET user_rate_limit_count = ?,
+    user_rate_limit_seconds = ?,
+    guild_rate_limit_count = ?,
+    guild_rate_limit_seconds = ?,
+    burst_threshold = ?,
+    burst_window_seconds = ?
+WHERE guild_id = ?
+            ",
+            db_uc,
+            db_us,
+            db_gc,
+            db_gs,
+            db_bt,
+            db_bw,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_case_window(
+        &self,
+        guild_id: GuildId,
+        case_window: Option<Duration>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_w = case_window.map(|w| w.as_secs() as i64);
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET case_window_seconds = ?
+WHERE guild_id = ?
+            ",
+            db_w,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_moderation_actions(
+        &self,
+        guild_id: GuildId,
+        moderation_actions: u8,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_ma = moderation_actions as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET moderation_actions = ?
+WHERE guild_id = ?
+            ",
+            db_ma,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_warning_dms(
+        &self,
+        guild_id: GuildId,
+        warn_reported_users: bool,
+        warning_template: Option<&str>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET warn_reported_users = ?, warning_template = ?
+WHERE guild_id = ?
+            ",
+            warn_reported_users,
+            warning_template,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
+
+    pub async fn save_escalation(
+        &self,
+        guild_id: GuildId,
+        mod_role_id: Option<RoleId>,
+        admin_role_id: Option<RoleId>,
+        unhandled_sla: Option<Duration>,
+        reviewing_sla: Option<Duration>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_mr = mod_role_id.map(|r| r.0 as i64);
+        let db_ar = admin_role_id.map(|r| r.0 as i64);
+        let db_us = unhandled_sla.map(|d| d.as_secs() as i64);
+        let db_rs = reviewing_sla.map(|d| d.as_secs() as i64);
+
+        sqlx::query!(
+            "
+UPDATE server_configuration
+SET mod_role_id = ?,
+    admin_role_id = ?,
+    unhandled_sla_seconds = ?,
+    reviewing_sla_seconds = ?
+WHERE guild_id = ?
+            ",
+            db_mr,
+            db_ar,
+            db_us,
+            db_rs,
+            db_gid
+        )
+        .execute(&self.connection)
+        .await?;
+
+        self.cache.wipe_server_config_cache(&guild_id).await;
+
+        Ok(())
+    }
 }
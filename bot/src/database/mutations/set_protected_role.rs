@@ -0,0 +1,50 @@
+use serenity::model::id::{GuildId, RoleId};
+
+use crate::database::Database;
+
+impl Database {
+    pub async fn protect_role(
+        &self,
+        guild_id: GuildId,
+        role_id: RoleId,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_rid = role_id.0 as i64;
+
+        sqlx::query!(
+            "
+INSERT OR REPLACE INTO protected_roles (guild_id, protected_role_id)
+VALUES (?, ?)
+            ",
+            db_gid,
+            db_rid,
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn unprotect_role(
+        &self,
+        guild_id: GuildId,
+        role_id: RoleId,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_rid = role_id.0 as i64;
+
+        sqlx::query!(
+            "
+DELETE FROM protected_roles
+WHERE guild_id = ?
+  AND protected_role_id = ?
+            ",
+            db_gid,
+            db_rid,
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+}
@@ -6,22 +6,27 @@ use serenity::model::{
 use crate::database::Database;
 
 impl Database {
+    /// Protects a user on behalf of `protected_by`. Protecting a user that's
+    /// already protected keeps the original record of who protected them.
     pub async fn protect_user(
         &self,
         guild_id: GuildId,
         user_id: UserId,
+        protected_by: UserId,
     ) -> Result<(), sqlx::Error> {
         let db_gid = guild_id.0 as i64;
         let db_uid = user_id.0 as i64;
+        let db_pid = protected_by.0 as i64;
 
         let mut transaction = self.connection.begin().await?;
         sqlx::query!(
-            "
-INSERT OR REPLACE INTO protected_users (guild_id, protected_user_id)
-VALUES (?, ?)
-            ",
+            r#"
+INSERT OR IGNORE INTO protected_users (guild_id, protected_user_id, protected_by_user_id, protected_at)
+VALUES (?, ?, ?, DATETIME("now"))
+            "#,
             db_gid,
             db_uid,
+            db_pid,
         )
         .execute(&mut transaction)
         .await?;
@@ -0,0 +1,63 @@
+use serenity::model::id::{GuildId, UserId};
+use std::time::Duration;
+
+use crate::database::Database;
+
+impl Database {
+    /// Bans a user from making reports, replacing any existing ban. Bans
+    /// without a duration last until they're lifted.
+    pub async fn report_ban_user(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+        banned_by: UserId,
+        reason: Option<&str>,
+        duration: Option<Duration>,
+    ) -> Result<(), sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = user_id.0 as i64;
+        let db_bid = banned_by.0 as i64;
+        let db_d = duration.map(|d| format!("+{} seconds", d.as_secs()));
+
+        sqlx::query!(
+            r#"
+INSERT OR REPLACE INTO report_banned_users (guild_id, banned_user_id, banned_by_user_id, reason, created, expires)
+VALUES (?, ?, ?, ?, DATETIME("now"), DATETIME("now", ?));
+            "#,
+            db_gid,
+            db_uid,
+            db_bid,
+            reason,
+            db_d
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(())
+    }
+
+    /// Lifts a user's report ban, returning whether they were banned.
+    pub async fn report_unban_user(
+        &self,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<bool, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = user_id.0 as i64;
+
+        let result = sqlx::query!(
+            r#"
+DELETE FROM report_banned_users
+WHERE guild_id = ?
+  AND banned_user_id = ?
+  AND (expires IS NULL OR expires > DATETIME("now"));
+            "#,
+            db_gid,
+            db_uid,
+        )
+        .execute(&self.connection)
+        .await?;
+
+        Ok(result.rows_affected() > 0)
+    }
+}
@@ -1,5 +1,9 @@
-use crate::database::{models::ReportStatus, Database};
+use crate::database::{
+    models::{ReportEventKind, ReportStatus},
+    Database,
+};
 use serenity::model::id::UserId;
+use sqlx::{pool::PoolConnection, Sqlite};
 use thiserror::Error;
 
 type ReportId = u64;
@@ -13,28 +17,88 @@ pub enum ReportUpdateError {
 }
 
 impl Database {
+    /// Updates the reason and/or status of a report on behalf of `actor`,
+    /// recording every value that changed in the report's history.
     pub async fn update_report<S: ToString>(
         &self,
         report_id: ReportId,
+        actor: UserId,
         reason: Option<S>,
         status: Option<ReportStatus>,
+    ) -> Result<(), ReportUpdateError> {
+        let reason = reason.map(|s| s.to_string());
+
+        let mut connection = self.connection.acquire().await?;
+
+        // we want exclusive access so the history matches what we overwrite
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::update_report_in_transaction(
+            &mut connection,
+            report_id,
+            actor,
+            reason,
+            status,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn update_report_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        actor: UserId,
+        reason: Option<String>,
+        status: Option<ReportStatus>,
     ) -> Result<(), ReportUpdateError> {
         let db_id = report_id as i64;
-        let db_r = reason.map(|s| s.to_string());
         let db_s = status.map(|s| Into::<i64>::into(s));
+        let db_accepted: i64 = ReportStatus::Accepted.into();
+        let db_denied: i64 = ReportStatus::Denied.into();
 
-        let result = sqlx::query!(
+        let previous = sqlx::query!(
             "
-UPDATE reports
-SET reason = COALESCE(?, reason),
-    status = COALESCE(?, status)
+SELECT reason, status FROM reports
 WHERE id = ?;
             ",
-            db_r,
+            db_id
+        )
+        .fetch_optional(&mut *connection)
+        .await?
+        .ok_or(ReportUpdateError::SurprisingRowUpdateCount(0))?;
+
+        let reason = reason.filter(|r| previous.reason.as_ref() != Some(r));
+        let db_s = db_s.filter(|s| *s != previous.status);
+
+        // leave `updated_at` alone if nothing would change
+        if reason.is_none() && db_s.is_none() {
+            return Ok(());
+        }
+
+        // a report is resolved the first time it's accepted or denied, and
+        // becomes unresolved if it's ever opened again
+        let result = sqlx::query!(
+            r#"
+UPDATE reports
+SET reason = COALESCE(?1, reason),
+    status = COALESCE(?2, status),
+    updated_at = DATETIME("now"),
+    resolved_at = CASE
+        WHEN COALESCE(?2, status) IN (?3, ?4) THEN COALESCE(resolved_at, DATETIME("now"))
+        ELSE NULL
+    END
+WHERE id = ?5;
+            "#,
+            reason,
             db_s,
+            db_accepted,
+            db_denied,
             db_id,
         )
-        .execute(&self.connection)
+        .execute(&mut *connection)
         .await?;
 
         if result.rows_affected() != 1 {
@@ -43,16 +107,77 @@ WHERE id = ?;
             ));
         }
 
+        if let Some(reason) = reason {
+            Database::record_report_event(
+                connection,
+                report_id,
+                Some(actor),
+                ReportEventKind::ReasonChanged,
+                previous.reason.as_deref(),
+                Some(&reason),
+            )
+            .await?;
+        }
+
+        if let Some(status) = db_s {
+            Database::record_report_event(
+                connection,
+                report_id,
+                Some(actor),
+                ReportEventKind::StatusChanged,
+                Some(&previous.status.to_string()),
+                Some(&status.to_string()),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 
+    /// Sets the moderator handling a report on behalf of `actor`, recording the
+    /// change in the report's history.
     pub async fn update_mod_view_handler(
         &self,
         report_id: ReportId,
-        moderator: UserId,
+        actor: UserId,
+        moderator: Option<UserId>,
+    ) -> Result<(), ReportUpdateError> {
+        let mut connection = self.connection.acquire().await?;
+
+        sqlx::query("BEGIN EXCLUSIVE;")
+            .execute(&mut connection)
+            .await?;
+
+        let result = Database::update_mod_view_handler_in_transaction(
+            &mut connection,
+            report_id,
+            actor,
+            moderator,
+        )
+        .await;
+
+        Database::end_transaction(&mut connection, result).await
+    }
+
+    async fn update_mod_view_handler_in_transaction(
+        connection: &mut PoolConnection<Sqlite>,
+        report_id: ReportId,
+        actor: UserId,
+        moderator: Option<UserId>,
     ) -> Result<(), ReportUpdateError> {
         let db_id = report_id as i64;
-        let db_h = moderator.0 as i64;
+        let db_h = moderator.map(|m| m.0 as i64);
+
+        let previous = sqlx::query!(
+            "
+SELECT handler FROM discord_mod_view
+WHERE report_id = ?;
+            ",
+            db_id
+        )
+        .fetch_optional(&mut *connection)
+        .await?
+        .ok_or(ReportUpdateError::SurprisingRowUpdateCount(0))?;
 
         let result = sqlx::query!(
             "
@@ -63,7 +188,7 @@ WHERE report_id = ?;
             db_h,
             db_id,
         )
-        .execute(&self.connection)
+        .execute(&mut *connection)
         .await?;
 
         if result.rows_affected() != 1 {
@@ -72,6 +197,18 @@ WHERE report_id = ?;
             ));
         }
 
+        if previous.handler != db_h {
+            Database::record_report_event(
+                connection,
+                report_id,
+                Some(actor),
+                ReportEventKind::HandlerChanged,
+                previous.handler.map(|h| h.to_string()).as_deref(),
+                db_h.map(|h| h.to_string()).as_deref(),
+            )
+            .await?;
+        }
+
         Ok(())
     }
 }
@@ -0,0 +1,64 @@
+use serenity::model::id::GuildId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    pub async fn load_archived_message_preview(
+        &self,
+        archive_id: u64,
+    ) -> Result<Option<ArchivedMessagePreviewModel>, sqlx::Error> {
+        let db_id = archive_id as i64;
+
+        let preview = sqlx::query!(
+            r#"
+SELECT id, content, archived, (
+    SELECT COUNT(*) FROM attachment_archive
+    WHERE attachment_archive.id = message_archive.id
+) AS "attachment_count!: i64"
+FROM message_archive
+WHERE id = ?;
+            "#,
+            db_id
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(preview.map(|p| ArchivedMessagePreviewModel {
+            id: p.id as u64,
+            content: p.content,
+            attachment_count: p.attachment_count as u64,
+            archived: p.archived.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+        }))
+    }
+
+    /// Computes how many bytes of attachments have been archived for reports
+    /// in a given server, so that the server's quota can be enforced.
+    pub async fn load_archived_attachment_usage(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<u64, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        let usage = sqlx::query!(
+            r#"
+SELECT COALESCE(SUM(LENGTH(attachment)), 0) AS "used!: i64" FROM attachment_archive
+WHERE id IN (
+    SELECT message_archive.id FROM message_archive
+    INNER JOIN reports ON reports.message_id = message_archive.message_id
+    WHERE reports.guild_id = ?
+    UNION
+    SELECT report_evidence.archive_id FROM report_evidence
+    INNER JOIN reports ON reports.id = report_evidence.report_id
+    WHERE reports.guild_id = ?
+);
+            "#,
+            db_gid,
+            db_gid
+        )
+        .fetch_one(&self.connection)
+        .await?;
+
+        Ok(usage.used as u64)
+    }
+}
@@ -0,0 +1,73 @@
+use serenity::model::id::*;
+use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
+
+use crate::database::{
+    models::{EscalationCandidateModel, ReportEventKind, ReportStatus},
+    Database,
+};
+
+impl Database {
+    /// Loads the open reports in a guild which have a mod view, along with
+    /// how long they've been in their status and how many times moderators
+    /// have been reminded about them since. Reports made before timestamps
+    /// were tracked are left out.
+    pub async fn load_escalation_candidates(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<Vec<EscalationCandidateModel>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_unhandled: i64 = ReportStatus::Unhandled.into();
+        let db_reviewing: i64 = ReportStatus::Reviewing.into();
+        let db_status_changed: i64 = ReportEventKind::StatusChanged.into();
+
+        let reports = sqlx::query!(
+            r#"
+SELECT r.id, r.status, v.channel_id, v.message_id, v.handler, v.thread_id,
+    COALESCE(
+        (SELECT MAX(created) FROM report_events WHERE report_id = r.id AND kind = ?),
+        r.created_at
+    ) AS "status_since: NaiveDateTime",
+    e.status AS "escalated_status?",
+    e.level AS "level?",
+    e.alerted_at AS "alerted_at?"
+FROM reports r
+JOIN discord_mod_view v ON v.report_id = r.id
+LEFT JOIN report_escalations e ON e.report_id = r.id
+WHERE r.guild_id = ?
+  AND r.status IN (?, ?);
+            "#,
+            db_status_changed,
+            db_gid,
+            db_unhandled,
+            db_reviewing
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(reports
+            .into_iter()
+            .filter_map(|r| {
+                let status_since = r.status_since?;
+
+                // reminders from before the report last changed status don't
+                // count any more
+                let (level, alerted_at) = match r.escalated_status == Some(r.status) {
+                    true => (r.level.unwrap_or(0) as u32, r.alerted_at),
+                    false => (0, None),
+                };
+
+                Some(EscalationCandidateModel {
+                    report_id: r.id as u64,
+                    status: r.status.into(),
+                    channel_id: ChannelId(r.channel_id as u64),
+                    message_id: MessageId(r.message_id as u64),
+                    handler: r.handler.map(|x| UserId(x as u64)),
+                    thread_id: r.thread_id.map(|x| ChannelId(x as u64)),
+                    status_since: DateTime::<Utc>::from_utc(status_since, Utc),
+                    level,
+                    alerted_at: alerted_at.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+                })
+            })
+            .collect())
+    }
+}
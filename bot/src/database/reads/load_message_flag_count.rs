@@ -0,0 +1,34 @@
+use serenity::model::id::{GuildId, MessageId};
+
+use crate::database::{models::ReportStatus, Database};
+
+impl Database {
+    /// Counts how many members have open reports against a message.
+    pub async fn load_message_flag_count(
+        &self,
+        guild_id: &GuildId,
+        message_id: &MessageId,
+    ) -> Result<u64, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+        let db_unhandled = Into::<i64>::into(ReportStatus::Unhandled);
+        let db_reviewing = Into::<i64>::into(ReportStatus::Reviewing);
+
+        let flags = sqlx::query!(
+            r#"
+SELECT COUNT(DISTINCT accuser_user_id) AS "count!: i64" FROM reports
+WHERE guild_id = ?
+  AND message_id = ?
+  AND status IN (?, ?);
+            "#,
+            db_gid,
+            db_mid,
+            db_unhandled,
+            db_reviewing
+        )
+        .fetch_one(&self.connection)
+        .await?;
+
+        Ok(flags.count as u64)
+    }
+}
@@ -0,0 +1,51 @@
+use serenity::model::id::MessageId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    pub async fn load_message_history(
+        &self,
+        message_id: &MessageId,
+    ) -> Result<MessageHistoryModel, sqlx::Error> {
+        let db_mid = message_id.0 as i64;
+
+        let revisions = sqlx::query!(
+            r#"
+SELECT id, content, archived, (
+    SELECT COUNT(*) FROM attachment_archive
+    WHERE attachment_archive.id = message_archive.id
+) AS "attachment_count!: i64"
+FROM message_archive
+WHERE message_id = ?
+ORDER BY id ASC;
+            "#,
+            db_mid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        let deleted = sqlx::query!(
+            "
+SELECT deleted FROM deleted_messages
+WHERE message_id = ?;
+            ",
+            db_mid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(MessageHistoryModel {
+            revisions: revisions
+                .into_iter()
+                .map(|r| ArchivedMessagePreviewModel {
+                    id: r.id as u64,
+                    content: r.content,
+                    attachment_count: r.attachment_count as u64,
+                    archived: r.archived.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+                })
+                .collect(),
+            deleted: deleted.map(|d| DateTime::<Utc>::from_utc(d.deleted, Utc)),
+        })
+    }
+}
@@ -21,6 +21,33 @@ impl Database {
             message_id: MessageId(r.message_id as u64),
             preview_archive_id: r.preview_archive_id as u64,
             handler: r.handler.map(|x| UserId(x as u64)),
+            thread_id: r.thread_id.map(|x| ChannelId(x as u64)),
+        }))
+    }
+
+    /// Loads the mod view of the report discussed in a thread.
+    pub async fn load_mod_view_by_thread(
+        &self,
+        thread_id: &ChannelId,
+    ) -> Result<Option<ModViewModel>, sqlx::Error> {
+        let db_tid = thread_id.0 as i64;
+
+        let report = sqlx::query!(
+            "
+ SELECT * FROM discord_mod_view WHERE thread_id = ?
+             ",
+            db_tid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(report.map(|r| ModViewModel {
+            report_id: r.report_id as u64,
+            channel_id: ChannelId(r.channel_id as u64),
+            message_id: MessageId(r.message_id as u64),
+            preview_archive_id: r.preview_archive_id as u64,
+            handler: r.handler.map(|x| UserId(x as u64)),
+            thread_id: r.thread_id.map(|x| ChannelId(x as u64)),
         }))
     }
 }
@@ -0,0 +1,55 @@
+use serenity::model::id::{GuildId, MessageId};
+
+use crate::database::{models::ReportStatus, Database};
+
+impl Database {
+    /// Loads the IDs of every report about a message that hasn't been accepted
+    /// or denied yet.
+    pub async fn load_open_reports_by_message(
+        &self,
+        message_id: &MessageId,
+    ) -> Result<Vec<u64>, sqlx::Error> {
+        let db_mid = message_id.0 as i64;
+        let db_unhandled: i64 = ReportStatus::Unhandled.into();
+        let db_reviewing: i64 = ReportStatus::Reviewing.into();
+
+        let reports = sqlx::query!(
+            "
+SELECT id FROM reports
+WHERE message_id = ?
+  AND status IN (?, ?);
+            ",
+            db_mid,
+            db_unhandled,
+            db_reviewing
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(reports.into_iter().map(|r| r.id as u64).collect())
+    }
+
+    pub async fn load_open_reports_by_guild(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<Vec<u64>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_unhandled: i64 = ReportStatus::Unhandled.into();
+        let db_reviewing: i64 = ReportStatus::Reviewing.into();
+
+        let reports = sqlx::query!(
+            "
+SELECT id FROM reports
+WHERE guild_id = ?
+  AND status IN (?, ?);
+            ",
+            db_gid,
+            db_unhandled,
+            db_reviewing
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(reports.into_iter().map(|r| r.id as u64).collect())
+    }
+}
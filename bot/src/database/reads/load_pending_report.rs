@@ -0,0 +1,21 @@
+use crate::database::Database;
+
+impl Database {
+    /// Checks if a report is being held back until its message has been
+    /// flagged by enough members.
+    pub async fn load_pending_report(&self, report_id: u64) -> Result<bool, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let result = sqlx::query!(
+            "
+SELECT report_id FROM pending_reports
+WHERE report_id = ?;
+            ",
+            db_rid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(result.is_some())
+    }
+}
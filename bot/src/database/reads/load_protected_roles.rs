@@ -0,0 +1,29 @@
+use serenity::model::id::{GuildId, RoleId};
+
+use crate::database::Database;
+
+impl Database {
+    pub async fn load_protected_roles(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<Vec<RoleId>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        let results = sqlx::query!(
+            "
+SELECT protected_role_id FROM protected_roles
+WHERE guild_id = ?;
+            ",
+            db_gid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        let roles = results
+            .into_iter()
+            .map(|r| RoleId(r.protected_role_id as u64))
+            .collect::<Vec<_>>();
+
+        Ok(roles)
+    }
+}
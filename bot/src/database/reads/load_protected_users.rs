@@ -1,18 +1,22 @@
 use serenity::model::id::{GuildId, UserId};
+use sqlx::types::chrono::{DateTime, Utc};
 
-use crate::database::Database;
+use crate::database::{models::ProtectedUserModel, Database};
 
 impl Database {
+    /// Loads every protected user in a server, most recently protected first.
+    /// Users protected before this was recorded come last.
     pub async fn load_protected_users(
         &self,
         guild_id: &GuildId,
-    ) -> Result<Vec<UserId>, sqlx::Error> {
+    ) -> Result<Vec<ProtectedUserModel>, sqlx::Error> {
         let db_gid = guild_id.0 as i64;
 
         let results = sqlx::query!(
             "
-SELECT protected_user_id FROM protected_users
-WHERE guild_id = ?;
+SELECT protected_user_id, protected_by_user_id, protected_at FROM protected_users
+WHERE guild_id = ?
+ORDER BY protected_at IS NULL, protected_at DESC;
             ",
             db_gid
         )
@@ -21,7 +25,11 @@ WHERE guild_id = ?;
 
         let users = results
             .into_iter()
-            .map(|u| UserId(u.protected_user_id as u64))
+            .map(|u| ProtectedUserModel {
+                user_id: UserId(u.protected_user_id as u64),
+                protected_by: u.protected_by_user_id.map(|id| UserId(id as u64)),
+                protected_at: u.protected_at.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+            })
             .collect::<Vec<_>>();
 
         Ok(users)
@@ -0,0 +1,33 @@
+use serenity::model::id::*;
+
+use crate::database::{models::RelayMessageModel, Database};
+
+impl Database {
+    /// Finds the relayed message the bot sent as a given message.
+    pub async fn load_relay_message(
+        &self,
+        channel_id: &ChannelId,
+        message_id: &MessageId,
+    ) -> Result<Option<RelayMessageModel>, sqlx::Error> {
+        let db_cid = channel_id.0 as i64;
+        let db_mid = message_id.0 as i64;
+
+        let relay = sqlx::query!(
+            "
+SELECT report_id, author_user_id, to_reporter FROM report_relay_messages
+WHERE message_id = ?
+  AND channel_id = ?;
+            ",
+            db_mid,
+            db_cid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(relay.map(|r| RelayMessageModel {
+            report_id: r.report_id as u64,
+            author: UserId(r.author_user_id as u64),
+            to_reporter: r.to_reporter,
+        }))
+    }
+}
@@ -1,12 +1,49 @@
 use serenity::model::id::*;
+use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
 
 use crate::database::{models::*, Database};
 
+/// A row of the `reports` table, as it's stored.
+pub(super) struct ReportRow {
+    pub id: i64,
+    pub accuser_user_id: i64,
+    pub reported_user_id: i64,
+    pub guild_id: i64,
+    pub status: i64,
+    pub channel_id: Option<i64>,
+    pub message_id: Option<i64>,
+    pub reason: Option<String>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub resolved_at: Option<NaiveDateTime>,
+    pub category_id: Option<i64>,
+}
+
+impl From<ReportRow> for ReportModel {
+    fn from(r: ReportRow) -> Self {
+        ReportModel {
+            id: r.id as u64,
+            accuser_user_id: UserId(r.accuser_user_id as u64),
+            reported_user_id: UserId(r.reported_user_id as u64),
+            guild_id: GuildId(r.guild_id as u64),
+            status: r.status.into(),
+            message_id: r.message_id.map(|x| MessageId(x as u64)),
+            channel_id: r.channel_id.map(|x| ChannelId(x as u64)),
+            reason: r.reason,
+            created_at: r.created_at.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+            updated_at: r.updated_at.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+            resolved_at: r.resolved_at.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+            category_id: r.category_id.map(|x| x as u64),
+        }
+    }
+}
+
 impl Database {
     pub async fn load_report(&self, report_id: u64) -> Result<Option<ReportModel>, sqlx::Error> {
         let db_id = report_id as i64;
 
-        let report = sqlx::query!(
+        let report = sqlx::query_as!(
+            ReportRow,
             "
 SELECT * FROM reports WHERE id = ?;
             ",
@@ -15,15 +52,6 @@ SELECT * FROM reports WHERE id = ?;
         .fetch_optional(&self.connection)
         .await?;
 
-        Ok(report.map(|r| ReportModel {
-            id: r.id as u64,
-            accuser_user_id: UserId(r.accuser_user_id as u64),
-            reported_user_id: UserId(r.reported_user_id as u64),
-            guild_id: GuildId(r.guild_id as u64),
-            status: r.status.into(),
-            message_id: r.message_id.map(|x| MessageId(x as u64)),
-            channel_id: r.channel_id.map(|x| ChannelId(x as u64)),
-            reason: r.reason,
-        }))
+        Ok(report.map(ReportModel::from))
     }
 }
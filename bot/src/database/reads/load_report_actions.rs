@@ -0,0 +1,50 @@
+use std::{convert::TryFrom, time::Duration};
+
+use serenity::model::id::UserId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the moderation actions taken on a report, oldest action first.
+    /// Actions of an unknown kind are skipped.
+    pub async fn load_report_actions(
+        &self,
+        report_id: u64,
+    ) -> Result<Vec<ReportActionModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let actions = sqlx::query!(
+            "
+SELECT * FROM report_actions
+WHERE report_id = ?
+ORDER BY id ASC;
+            ",
+            db_rid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(actions
+            .into_iter()
+            .filter_map(|a| {
+                let action = match ModerationActionKind::try_from(a.kind).ok()? {
+                    ModerationActionKind::DeleteMessage => ModerationAction::DeleteMessage,
+                    ModerationActionKind::Timeout => ModerationAction::Timeout(
+                        Duration::from_secs(a.duration_seconds.unwrap_or(0) as u64),
+                    ),
+                    ModerationActionKind::Kick => ModerationAction::Kick,
+                    ModerationActionKind::Ban => ModerationAction::Ban,
+                };
+
+                Some(ReportActionModel {
+                    id: a.id as u64,
+                    report_id: a.report_id as u64,
+                    moderator: UserId(a.moderator_user_id as u64),
+                    action,
+                    created: DateTime::<Utc>::from_utc(a.created, Utc),
+                })
+            })
+            .collect())
+    }
+}
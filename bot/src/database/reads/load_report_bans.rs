@@ -0,0 +1,69 @@
+use serenity::model::id::{GuildId, UserId};
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the ban preventing a user from making reports, if they're banned.
+    /// Expired bans are ignored.
+    pub async fn load_report_ban(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+    ) -> Result<Option<ReportBanModel>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = user_id.0 as i64;
+
+        let ban = sqlx::query!(
+            r#"
+SELECT * FROM report_banned_users
+WHERE guild_id = ?
+  AND banned_user_id = ?
+  AND (expires IS NULL OR expires > DATETIME("now"));
+            "#,
+            db_gid,
+            db_uid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(ban.map(|b| ReportBanModel {
+            user_id: UserId(b.banned_user_id as u64),
+            banned_by: UserId(b.banned_by_user_id as u64),
+            reason: b.reason,
+            created: DateTime::<Utc>::from_utc(b.created, Utc),
+            expires: b.expires.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+        }))
+    }
+
+    /// Loads every user banned from making reports, most recent ban first.
+    pub async fn load_report_bans(
+        &self,
+        guild_id: &GuildId,
+    ) -> Result<Vec<ReportBanModel>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+
+        let bans = sqlx::query!(
+            r#"
+SELECT * FROM report_banned_users
+WHERE guild_id = ?
+  AND (expires IS NULL OR expires > DATETIME("now"))
+ORDER BY created DESC;
+            "#,
+            db_gid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(bans
+            .into_iter()
+            .map(|b| ReportBanModel {
+                user_id: UserId(b.banned_user_id as u64),
+                banned_by: UserId(b.banned_by_user_id as u64),
+                reason: b.reason,
+                created: DateTime::<Utc>::from_utc(b.created, Utc),
+                expires: b.expires.map(|t| DateTime::<Utc>::from_utc(t, Utc)),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,103 @@
+use serenity::model::id::*;
+use sqlx::types::chrono::{DateTime, NaiveDateTime, Utc};
+
+use super::load_report::ReportRow;
+use crate::database::{models::*, Database};
+
+/// A row of the `report_bursts` table, as it's stored.
+struct ReportBurstRow {
+    id: i64,
+    guild_id: i64,
+    reported_user_id: i64,
+    channel_id: Option<i64>,
+    message_id: Option<i64>,
+    created_at: NaiveDateTime,
+    updated_at: NaiveDateTime,
+    thread_id: Option<i64>,
+}
+
+impl From<ReportBurstRow> for ReportBurstModel {
+    fn from(b: ReportBurstRow) -> Self {
+        ReportBurstModel {
+            id: b.id as u64,
+            guild_id: GuildId(b.guild_id as u64),
+            reported_user_id: UserId(b.reported_user_id as u64),
+            channel_id: b.channel_id.map(|x| ChannelId(x as u64)),
+            message_id: b.message_id.map(|x| MessageId(x as u64)),
+            created_at: DateTime::<Utc>::from_utc(b.created_at, Utc),
+            updated_at: DateTime::<Utc>::from_utc(b.updated_at, Utc),
+            thread_id: b.thread_id.map(|x| ChannelId(x as u64)),
+        }
+    }
+}
+
+impl Database {
+    pub async fn load_burst(&self, burst_id: u64) -> Result<Option<ReportBurstModel>, sqlx::Error> {
+        let db_bid = burst_id as i64;
+
+        let burst = sqlx::query_as!(
+            ReportBurstRow,
+            "
+SELECT * FROM report_bursts WHERE id = ?;
+            ",
+            db_bid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(burst.map(ReportBurstModel::from))
+    }
+
+    /// Loads the burst a report was collapsed into, if it was.
+    pub async fn load_report_burst(
+        &self,
+        report_id: u64,
+    ) -> Result<Option<ReportBurstModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let burst = sqlx::query_as!(
+            ReportBurstRow,
+            "
+SELECT * FROM report_bursts
+WHERE id = (SELECT burst_id FROM report_burst_reports WHERE report_id = ?);
+            ",
+            db_rid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(burst.map(ReportBurstModel::from))
+    }
+
+    /// Loads every report in a burst, oldest report first. Reports grouped
+    /// into a case led by a report in the burst are included, since the case
+    /// has no mod view of its own. Reports that are held back aren't included.
+    pub async fn load_burst_reports(&self, burst_id: u64) -> Result<Vec<ReportModel>, sqlx::Error> {
+        let db_bid = burst_id as i64;
+
+        let reports = sqlx::query_as!(
+            ReportRow,
+            "
+SELECT * FROM reports
+WHERE (
+       id IN (SELECT report_id FROM report_burst_reports WHERE burst_id = ?)
+    OR id IN (
+        SELECT report_id FROM report_case_reports
+        WHERE case_id IN (
+            SELECT id FROM report_cases
+            WHERE lead_report_id IN (SELECT report_id FROM report_burst_reports WHERE burst_id = ?)
+        )
+    )
+  )
+  AND id NOT IN (SELECT report_id FROM pending_reports)
+ORDER BY id ASC;
+            ",
+            db_bid,
+            db_bid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(reports.into_iter().map(ReportModel::from).collect())
+    }
+}
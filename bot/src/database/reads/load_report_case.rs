@@ -0,0 +1,55 @@
+use serenity::model::id::*;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use super::load_report::ReportRow;
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the case a report was grouped into, if it was.
+    pub async fn load_report_case(
+        &self,
+        report_id: u64,
+    ) -> Result<Option<ReportCaseModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let case = sqlx::query!(
+            "
+SELECT * FROM report_cases
+WHERE id = (SELECT case_id FROM report_case_reports WHERE report_id = ?);
+            ",
+            db_rid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(case.map(|c| ReportCaseModel {
+            id: c.id as u64,
+            guild_id: GuildId(c.guild_id as u64),
+            reported_user_id: UserId(c.reported_user_id as u64),
+            message_id: c.message_id.map(|x| MessageId(x as u64)),
+            lead_report_id: c.lead_report_id as u64,
+            created_at: DateTime::<Utc>::from_utc(c.created_at, Utc),
+        }))
+    }
+
+    /// Loads every report in a case, oldest report first. Reports that are
+    /// held back aren't included.
+    pub async fn load_case_reports(&self, case_id: u64) -> Result<Vec<ReportModel>, sqlx::Error> {
+        let db_cid = case_id as i64;
+
+        let reports = sqlx::query_as!(
+            ReportRow,
+            "
+SELECT * FROM reports
+WHERE id IN (SELECT report_id FROM report_case_reports WHERE case_id = ?)
+  AND id NOT IN (SELECT report_id FROM pending_reports)
+ORDER BY id ASC;
+            ",
+            db_cid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(reports.into_iter().map(ReportModel::from).collect())
+    }
+}
@@ -0,0 +1,43 @@
+use std::convert::TryFrom;
+
+use serenity::model::id::UserId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the audit log of a report, oldest event first. Events of an
+    /// unknown kind are skipped.
+    pub async fn load_report_events(
+        &self,
+        report_id: u64,
+    ) -> Result<Vec<ReportEventModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let events = sqlx::query!(
+            "
+SELECT * FROM report_events
+WHERE report_id = ?
+ORDER BY id ASC;
+            ",
+            db_rid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(events
+            .into_iter()
+            .filter_map(|e| {
+                Some(ReportEventModel {
+                    id: e.id as u64,
+                    report_id: e.report_id as u64,
+                    actor: e.actor_user_id.map(|a| UserId(a as u64)),
+                    kind: ReportEventKind::try_from(e.kind).ok()?,
+                    old_value: e.old_value,
+                    new_value: e.new_value,
+                    created: DateTime::<Utc>::from_utc(e.created, Utc),
+                })
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,57 @@
+use std::collections::HashMap;
+
+use serenity::model::id::*;
+
+use crate::database::{models::ReportEvidenceModel, Database};
+
+impl Database {
+    pub async fn load_report_evidence(
+        &self,
+        report_id: u64,
+    ) -> Result<Vec<ReportEvidenceModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let evidence = sqlx::query!(
+            "
+SELECT e.idx, e.channel_id, e.message_id, e.archive_id, e.is_linked, m.content
+FROM report_evidence e
+INNER JOIN message_archive m ON m.id = e.archive_id
+WHERE e.report_id = ?
+ORDER BY e.idx ASC;
+            ",
+            db_rid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        let attachments = sqlx::query!(
+            "
+SELECT a.id, a.url FROM attachment_archive a
+INNER JOIN report_evidence e ON e.archive_id = a.id
+WHERE e.report_id = ?
+ORDER BY a.id ASC, a.idx ASC;
+            ",
+            db_rid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        let mut urls = HashMap::<i64, Vec<String>>::new();
+        for attachment in attachments {
+            urls.entry(attachment.id).or_default().push(attachment.url);
+        }
+
+        Ok(evidence
+            .into_iter()
+            .map(|e| ReportEvidenceModel {
+                report_id,
+                idx: e.idx as u64,
+                channel_id: ChannelId(e.channel_id as u64),
+                message_id: MessageId(e.message_id as u64),
+                is_linked: e.is_linked,
+                content: e.content,
+                attachment_urls: urls.remove(&e.archive_id).unwrap_or_default(),
+            })
+            .collect())
+    }
+}
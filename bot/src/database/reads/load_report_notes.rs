@@ -0,0 +1,36 @@
+use serenity::model::id::UserId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the notes moderators wrote on a report, oldest note first.
+    pub async fn load_report_notes(
+        &self,
+        report_id: u64,
+    ) -> Result<Vec<ReportNoteModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let notes = sqlx::query!(
+            "
+SELECT * FROM report_notes
+WHERE report_id = ?
+ORDER BY id ASC;
+            ",
+            db_rid
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        Ok(notes
+            .into_iter()
+            .map(|n| ReportNoteModel {
+                id: n.id as u64,
+                report_id: n.report_id as u64,
+                author: UserId(n.author_user_id as u64),
+                content: n.content,
+                created: DateTime::<Utc>::from_utc(n.created, Utc),
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,31 @@
+use serenity::model::id::UserId;
+use sqlx::types::chrono::{DateTime, Utc};
+
+use crate::database::{models::ReportWarningModel, Database};
+
+impl Database {
+    pub async fn load_report_warning(
+        &self,
+        report_id: u64,
+    ) -> Result<Option<ReportWarningModel>, sqlx::Error> {
+        let db_rid = report_id as i64;
+
+        let warning = sqlx::query!(
+            "
+SELECT * FROM report_warnings
+WHERE report_id = ?;
+            ",
+            db_rid
+        )
+        .fetch_optional(&self.connection)
+        .await?;
+
+        Ok(warning.map(|w| ReportWarningModel {
+            report_id: w.report_id as u64,
+            moderator: UserId(w.moderator_user_id as u64),
+            rule: w.rule,
+            note: w.note,
+            sent: w.sent.map(|sent| DateTime::<Utc>::from_utc(sent, Utc)),
+        }))
+    }
+}
@@ -0,0 +1,42 @@
+use serenity::model::id::{GuildId, UserId};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    pub async fn load_reported_user_summary(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+    ) -> Result<ReportedUserSummaryModel, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = user_id.0 as i64;
+        let db_accepted: i64 = ReportStatus::Accepted.into();
+        let db_denied: i64 = ReportStatus::Denied.into();
+
+        let summary = sqlx::query!(
+            r#"
+SELECT
+    COUNT(*) AS "total!: i64",
+    COALESCE(SUM(status = ?), 0) AS "accepted!: i64",
+    COALESCE(SUM(status = ?), 0) AS "denied!: i64",
+    COUNT(DISTINCT accuser_user_id) AS "reporters!: i64"
+FROM reports
+WHERE guild_id = ?
+  AND reported_user_id = ?;
+            "#,
+            db_accepted,
+            db_denied,
+            db_gid,
+            db_uid
+        )
+        .fetch_one(&self.connection)
+        .await?;
+
+        Ok(ReportedUserSummaryModel {
+            total: summary.total as u64,
+            accepted: summary.accepted as u64,
+            denied: summary.denied as u64,
+            reporters: summary.reporters as u64,
+        })
+    }
+}
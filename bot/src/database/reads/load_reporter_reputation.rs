@@ -0,0 +1,38 @@
+use serenity::model::id::{GuildId, UserId};
+
+use crate::database::{models::*, Database};
+
+impl Database {
+    pub async fn load_reporter_reputation(
+        &self,
+        guild_id: &GuildId,
+        user_id: &UserId,
+    ) -> Result<ReporterReputationModel, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_uid = user_id.0 as i64;
+        let db_accepted: i64 = ReportStatus::Accepted.into();
+        let db_denied: i64 = ReportStatus::Denied.into();
+
+        let reputation = sqlx::query!(
+            r#"
+SELECT
+    COALESCE(SUM(status = ?), 0) AS "accepted!: i64",
+    COALESCE(SUM(status = ?), 0) AS "denied!: i64"
+FROM reports
+WHERE guild_id = ?
+  AND accuser_user_id = ?;
+            "#,
+            db_accepted,
+            db_denied,
+            db_gid,
+            db_uid
+        )
+        .fetch_one(&self.connection)
+        .await?;
+
+        Ok(ReporterReputationModel {
+            accepted: reputation.accepted as u64,
+            denied: reputation.denied as u64,
+        })
+    }
+}
@@ -1,6 +1,8 @@
-use serenity::model::id::GuildId;
+use std::time::Duration;
 
-use crate::database::models::ServerConfiguration;
+use serenity::model::id::{ChannelId, GuildId, RoleId};
+
+use crate::database::models::{RateLimit, ReportCategoryModel, ServerConfiguration};
 use crate::database::Database;
 
 impl Database {
@@ -19,11 +21,67 @@ WHERE guild_id = ?;
         .fetch_optional(&self.connection)
         .await?;
 
-        Ok(server.map(|server| ServerConfiguration {
+        let server = match server {
+            Some(server) => server,
+            None => return Ok(None),
+        };
+
+        let categories = sqlx::query!(
+            "
+SELECT * FROM report_categories
+WHERE guild_id = ?
+ORDER BY name ASC;
+            ",
+            guild_id
+        )
+        .fetch_all(&self.connection)
+        .await?;
+
+        let categories = (categories.into_iter())
+            .map(|c| ReportCategoryModel {
+                id: c.id as u64,
+                guild_id: GuildId(c.guild_id as u64),
+                name: c.name,
+                emoji_builtin: c.emoji_builtin,
+                emoji_custom: c.emoji_custom.map(|n| n as u64),
+                channel_id: c.channel_id.map(|n| ChannelId(n as u64)),
+                ping_role_id: c.ping_role_id.map(|n| RoleId(n as u64)),
+            })
+            .collect();
+
+        Ok(Some(ServerConfiguration {
             emoji_builtin: server.emoji_builtin,
             emoji_custom: server.emoji_custom.map(|n| n as u64),
             prefix: server.prefix,
             reports_channel: server.reports_channel as u64,
+            attachment_max_size: server.attachment_max_size.map(|n| n as u64),
+            attachment_quota: server.attachment_quota.map(|n| n as u64),
+            mod_view_reactions: server.mod_view_reactions,
+            reputation_threshold: server.reputation_threshold.map(|n| n as u8),
+            reputation_requires_reason: server.reputation_requires_reason,
+            user_rate_limit: RateLimit {
+                count: server.user_rate_limit_count as u32,
+                per: Duration::from_secs(server.user_rate_limit_seconds as u64),
+            },
+            guild_rate_limit: RateLimit {
+                count: server.guild_rate_limit_count as u32,
+                per: Duration::from_secs(server.guild_rate_limit_seconds as u64),
+            },
+            burst_threshold: server.burst_threshold as u32,
+            burst_window: Duration::from_secs(server.burst_window_seconds as u64),
+            case_window: (server.case_window_seconds).map(|n| Duration::from_secs(n as u64)),
+            report_threshold: server.report_threshold as u32,
+            auto_hide_threshold: server.auto_hide_threshold.map(|n| n as u32),
+            moderation_actions: server.moderation_actions as u8,
+            warn_reported_users: server.warn_reported_users,
+            warning_template: server.warning_template,
+            categories,
+            mod_role_id: server.mod_role_id.map(|n| RoleId(n as u64)),
+            admin_role_id: server.admin_role_id.map(|n| RoleId(n as u64)),
+            unhandled_sla: (server.unhandled_sla_seconds).map(|n| Duration::from_secs(n as u64)),
+            reviewing_sla: (server.reviewing_sla_seconds).map(|n| Duration::from_secs(n as u64)),
+            mod_view_threads: server.mod_view_threads,
+            relay_anonymous: server.relay_anonymous,
         }))
     }
 }
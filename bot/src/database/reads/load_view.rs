@@ -31,6 +31,7 @@ WHERE message_id = ?
                 message_id: MessageId(r.message_id as u64),
                 preview_archive_id: r.preview_archive_id as u64,
                 handler: r.handler.map(|x| UserId(x as u64)),
+                thread_id: r.thread_id.map(|x| ChannelId(x as u64)),
             })));
         }
 
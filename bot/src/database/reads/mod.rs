@@ -1,8 +1,27 @@
+mod load_archived_message;
+mod load_escalation_candidates;
+mod load_message_flag_count;
+mod load_message_history;
 mod load_mod_view;
+mod load_open_reports;
+mod load_pending_report;
+mod load_protected_roles;
 mod load_protected_user;
 mod load_protected_users;
+mod load_relay_message;
 mod load_report;
+mod load_report_actions;
+mod load_report_bans;
+mod load_report_burst;
+mod load_report_case;
+mod load_report_events;
+mod load_report_evidence;
+mod load_report_notes;
+mod load_report_warning;
+mod load_reported_user_summary;
+mod load_reporter_reputation;
 mod load_server_config;
 mod load_user_view;
 mod load_view;
 mod load_welcome_time;
+mod search_reports;
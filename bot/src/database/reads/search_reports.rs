@@ -0,0 +1,157 @@
+use serenity::model::id::GuildId;
+
+use super::load_report::ReportRow;
+use crate::database::{models::*, Database};
+
+impl Database {
+    /// Loads the most recent reports in a server matching the filter, newest
+    /// report first. Each filter has its own query so that it can make use of
+    /// its index.
+    pub async fn search_reports(
+        &self,
+        guild_id: &GuildId,
+        filter: &ReportFilter,
+        limit: u64,
+    ) -> Result<Vec<ReportModel>, sqlx::Error> {
+        let db_gid = guild_id.0 as i64;
+        let db_limit = limit as i64;
+
+        let reports = match filter {
+            ReportFilter::All => {
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::Status(status) => {
+                let db_s = Into::<i64>::into(*status);
+
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+  AND status = ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_s,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::ReportedUser(user_id) => {
+                let db_uid = user_id.0 as i64;
+
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+  AND reported_user_id = ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_uid,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::Accuser(user_id) => {
+                let db_uid = user_id.0 as i64;
+
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+  AND accuser_user_id = ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_uid,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::Handler(user_id) => {
+                let db_uid = user_id.0 as i64;
+
+                sqlx::query_as!(
+                    ReportRow,
+                    r#"
+SELECT reports.* FROM reports
+INNER JOIN discord_mod_view ON discord_mod_view.report_id = reports.id
+WHERE discord_mod_view.handler = ?
+  AND reports.guild_id = ?
+ORDER BY reports.id DESC
+LIMIT ?;
+                    "#,
+                    db_uid,
+                    db_gid,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::Channel(channel_id) => {
+                let db_cid = channel_id.0 as i64;
+
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+  AND channel_id = ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_cid,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+            ReportFilter::Created(start, end) => {
+                let db_start = start.naive_utc();
+                let db_end = end.naive_utc();
+
+                sqlx::query_as!(
+                    ReportRow,
+                    "
+SELECT * FROM reports
+WHERE guild_id = ?
+  AND created_at >= ?
+  AND created_at < ?
+ORDER BY id DESC
+LIMIT ?;
+                    ",
+                    db_gid,
+                    db_start,
+                    db_end,
+                    db_limit
+                )
+                .fetch_all(&self.connection)
+                .await?
+            }
+        };
+
+        Ok(reports.into_iter().map(ReportModel::from).collect())
+    }
+}
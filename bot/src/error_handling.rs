@@ -5,7 +5,7 @@ use serenity::{
 
 use thiserror::Error;
 
-pub async fn handle_err_dms<E: std::fmt::Display, M: ToString>(
+pub async fn handle_err_dms<E: std::fmt::Display + ?Sized, M: ToString>(
     ctx: &Context,
     user_id: UserId,
     problematic_msg: Option<MessageId>,
@@ -48,7 +48,7 @@ enum HandleErrError {
     CouldntSend(ChannelId, serenity::Error, String),
 }
 
-pub async fn handle_err<E: std::fmt::Display, M: ToString>(
+pub async fn handle_err<E: std::fmt::Display + ?Sized, M: ToString>(
     ctx: &Context,
     channel_id: ChannelId,
     problematic_msg: Option<MessageId>,
@@ -76,7 +76,7 @@ pub async fn handle_err<E: std::fmt::Display, M: ToString>(
     };
 }
 
-async fn internal_handle_err<E: std::fmt::Display, M: ToString>(
+async fn internal_handle_err<E: std::fmt::Display + ?Sized, M: ToString>(
     ctx: &Context,
     channel_id: ChannelId,
     problematic_msg: Option<MessageId>,
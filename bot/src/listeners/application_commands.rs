@@ -0,0 +1,108 @@
+//! Registers the application commands (slash commands and context menus) that
+//! users can report with, in every guild Narc is in. The `/report` command
+//! lets users choose from the guild's report categories, so it's registered
+//! again whenever they change.
+
+use serenity::{
+    client::Context,
+    model::{
+        application::command::{CommandOptionType, CommandType},
+        id::GuildId,
+    },
+};
+
+use crate::{
+    database::{models::ReportCategoryModel, Database},
+    services::REASON_MAX_LENGTH,
+};
+
+pub const REPORT_COMMAND: &str = "report";
+pub const REPORT_MESSAGE_COMMAND: &str = "Report Message";
+pub const REPORT_USER_COMMAND: &str = "Report User";
+
+/// The most choices Discord allows an option to have.
+const MAX_CHOICES: usize = 25;
+
+pub async fn ready(ctx: &Context, guilds: impl Iterator<Item = GuildId>) {
+    for guild_id in guilds {
+        register(ctx, guild_id).await;
+    }
+}
+
+pub async fn guild_create(ctx: &Context, guild_id: GuildId) {
+    register(ctx, guild_id).await;
+}
+
+pub async fn register(ctx: &Context, guild_id: GuildId) {
+    let categories = load_categories(ctx, guild_id).await;
+
+    let result = guild_id
+        .set_application_commands(ctx, |commands| {
+            commands
+                .create_application_command(|c| {
+                    c.name(REPORT_COMMAND)
+                        .description("Submits a report on a user")
+                        .kind(CommandType::ChatInput)
+                        .create_option(|o| {
+                            o.name("user")
+                                .description("The user to report")
+                                .kind(CommandOptionType::User)
+                                .required(true)
+                        })
+                        .create_option(|o| {
+                            o.name("reason")
+                                .description("Why you are reporting this user")
+                                .kind(CommandOptionType::String)
+                                .max_length(REASON_MAX_LENGTH as u16)
+                                .required(false)
+                        });
+
+                    if !categories.is_empty() {
+                        c.create_option(|o| {
+                            o.name("category")
+                                .description("What kind of report this is")
+                                .kind(CommandOptionType::String)
+                                .required(false);
+
+                            for category in categories.iter().take(MAX_CHOICES) {
+                                o.add_string_choice(&category.name, category.id);
+                            }
+
+                            o
+                        });
+                    }
+
+                    c
+                })
+                .create_application_command(|c| {
+                    c.name(REPORT_MESSAGE_COMMAND).kind(CommandType::Message)
+                })
+                .create_application_command(|c| c.name(REPORT_USER_COMMAND).kind(CommandType::User))
+        })
+        .await;
+
+    if let Err(error) = result {
+        log::error!(
+            "error while registering application commands in '{}': {}",
+            guild_id,
+            error
+        );
+    }
+}
+
+async fn load_categories(ctx: &Context, guild_id: GuildId) -> Vec<ReportCategoryModel> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    match db.get_server_config(&guild_id).await {
+        Ok(config) => config.map(|c| c.categories).unwrap_or_default(),
+        Err(error) => {
+            log::error!(
+                "error while loading report categories in '{}': {}",
+                guild_id,
+                error
+            );
+            Vec::new()
+        }
+    }
+}
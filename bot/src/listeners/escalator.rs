@@ -0,0 +1,180 @@
+//! Reminds moderators about reports that have been left unhandled or under
+//! review for longer than the server allows. The first reminder pings the
+//! moderator role (or DMs the moderator reviewing the report), and later
+//! reminders escalate to the admin role.
+
+use std::{sync::atomic::Ordering, time::Duration};
+
+use serenity::{
+    client::Context,
+    model::id::{GuildId, RoleId, UserId},
+    prelude::Mentionable,
+};
+use sqlx::types::chrono::Utc;
+
+use crate::{
+    database::{
+        models::{EscalationCandidateModel, ReportStatus, ServerConfiguration},
+        Database,
+    },
+    state::State,
+};
+
+/// How often open reports are checked.
+const ESCALATION_INTERVAL: Duration = Duration::from_secs(60);
+
+pub async fn ready(ctx: &Context) {
+    let data = ctx.data.read().await;
+    let state = data.get::<State>().unwrap();
+
+    if state.escalator_started.swap(true, Ordering::SeqCst) {
+        return;
+    }
+
+    let ctx = ctx.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(ESCALATION_INTERVAL);
+
+        loop {
+            interval.tick().await;
+
+            for guild_id in ctx.cache.guilds() {
+                if let Err(error) = escalate_guild(&ctx, guild_id).await {
+                    log::error!(
+                        "error while escalating reports in '{}': {}",
+                        guild_id,
+                        error
+                    );
+                }
+            }
+        }
+    });
+}
+
+async fn escalate_guild(ctx: &Context, guild_id: GuildId) -> Result<(), sqlx::Error> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let config = match db.get_server_config(&guild_id).await? {
+        Some(config) if config.unhandled_sla.is_some() || config.reviewing_sla.is_some() => config,
+        _ => return Ok(()),
+    };
+
+    let now = Utc::now();
+
+    for report in db.load_escalation_candidates(&guild_id).await? {
+        let sla = match report.status {
+            ReportStatus::Unhandled => config.unhandled_sla,
+            _ => config.reviewing_sla,
+        };
+
+        let sla = match sla {
+            Some(sla) => sla,
+            None => continue,
+        };
+
+        // every reminder waits out the SLA again
+        let last_alert = report.alerted_at.unwrap_or(report.status_since);
+        if now.timestamp() - last_alert.timestamp() < sla.as_secs() as i64 {
+            continue;
+        }
+
+        let level = report.level + 1;
+
+        // a failed reminder is retried on the next check
+        match alert(ctx, &config, &report, level).await {
+            Ok(_) => {
+                db.save_report_escalation(report.report_id, report.status, level)
+                    .await?
+            }
+            Err(error) => log::warn!(
+                "couldn't send reminder about report '{}': {}",
+                report.report_id,
+                error
+            ),
+        };
+    }
+
+    Ok(())
+}
+
+async fn alert(
+    ctx: &Context,
+    config: &ServerConfiguration,
+    report: &EscalationCandidateModel,
+    level: u32,
+) -> serenity::Result<()> {
+    let waiting = match report.status {
+        ReportStatus::Unhandled => "has been waiting for a moderator",
+        _ => "has been under review",
+    };
+    let since = report.status_since.timestamp();
+    let url = report.message_id.link(report.channel_id, None);
+
+    // the moderator reviewing the report is reminded first
+    if let (1, ReportStatus::Reviewing, Some(handler)) = (level, report.status, report.handler) {
+        if remind_handler(ctx, handler, report, &url).await.is_ok() {
+            return Ok(());
+        }
+    }
+
+    let (emoji, role) = match level {
+        1 => ("⏰", config.mod_role_id),
+        _ => ("🚨", config.admin_role_id.or(config.mod_role_id)),
+    };
+
+    let mut content = String::new();
+    let mut users = Vec::new();
+
+    if let Some(role_id) = role {
+        content.push_str(&format!("{} ", role_id.mention()));
+    } else if let Some(handler) = report.handler {
+        content.push_str(&format!("{} ", handler.mention()));
+        users.push(handler);
+    }
+
+    content.push_str(&format!(
+        "{} Report #{} {} since <t:{}:R>.",
+        emoji, report.report_id, waiting, since
+    ));
+
+    let roles: Vec<RoleId> = role.into_iter().collect();
+
+    // reminders are sent in the report's thread, so they don't bury other
+    // reports
+    let channel_id = report.thread_id.unwrap_or(report.channel_id);
+
+    channel_id
+        .send_message(ctx, |m| {
+            if channel_id == report.channel_id {
+                m.reference_message((report.channel_id, report.message_id));
+            }
+
+            m.content(content)
+                .allowed_mentions(|a| a.roles(roles).users(users))
+        })
+        .await?;
+
+    Ok(())
+}
+
+async fn remind_handler(
+    ctx: &Context,
+    handler: UserId,
+    report: &EscalationCandidateModel,
+    url: &str,
+) -> serenity::Result<()> {
+    let dms = handler.create_dm_channel(ctx).await?;
+
+    dms.send_message(ctx, |m| {
+        m.content(format!(
+            "⏰ Report #{}, which you claimed, has been under review since <t:{}:R>: {}",
+            report.report_id,
+            report.status_since.timestamp(),
+            url
+        ))
+    })
+    .await?;
+
+    Ok(())
+}
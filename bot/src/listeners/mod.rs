@@ -1,11 +1,24 @@
 use serenity::client::{Context, EventHandler};
-use serenity::{async_trait, model::prelude::*};
+use serenity::{
+    async_trait,
+    model::{application::interaction::Interaction, prelude::*},
+};
 
 use crate::error_handling::handle_err;
 
+pub(crate) mod application_commands;
+mod escalator;
+mod modal;
+mod note_modal;
+mod on_component;
+mod on_interaction;
+mod on_message_change;
 mod on_msg;
 mod on_reaction;
+mod reason_modal;
+pub(crate) mod relay;
 mod status_updator;
+mod warning_modal;
 mod welcomer;
 
 pub struct Listener;
@@ -15,12 +28,15 @@ impl EventHandler for Listener {
     async fn ready(&self, ctx: Context, data_about_bot: Ready) {
         status_updator::ready(&ctx, &data_about_bot).await;
         welcomer::ready(&ctx, data_about_bot.guilds.iter().map(|s| s.id)).await;
+        application_commands::ready(&ctx, data_about_bot.guilds.iter().map(|s| s.id)).await;
+        escalator::ready(&ctx).await;
     }
 
     async fn guild_create(&self, ctx: Context, guild: Guild, is_new: bool) {
         if is_new {
             status_updator::guild_create(&ctx).await;
             welcomer::guild_create(&ctx, &guild).await;
+            application_commands::guild_create(&ctx, guild.id).await;
         }
     }
 
@@ -55,6 +71,14 @@ impl EventHandler for Listener {
         }
     }
 
+    async fn interaction_create(&self, ctx: Context, interaction: Interaction) {
+        // where possible, errors are shown to the user privately as the
+        // interaction's response
+        if let Err(error) = on_interaction::interaction_create(&ctx, &interaction).await {
+            log::error!("error while handling interaction: {}", error);
+        }
+    }
+
     async fn message(&self, ctx: Context, message: Message) {
         println!("msg is {message:?}");
         if let Err(error) = on_msg::message(&ctx, &message).await {
@@ -67,5 +91,61 @@ impl EventHandler for Listener {
             )
             .await
         }
+
+        if let Err(error) = relay::message(&ctx, &message).await {
+            handle_err(
+                &ctx,
+                message.channel_id,
+                None,
+                &error,
+                "An error occurred while relaying your message",
+            )
+            .await
+        }
+    }
+
+    async fn message_update(
+        &self,
+        ctx: Context,
+        _old_if_available: Option<Message>,
+        new: Option<Message>,
+        event: MessageUpdateEvent,
+    ) {
+        // errors aren't sent to the channel, since that would reveal that the
+        // message was reported
+        if let Err(error) = on_message_change::message_update(&ctx, new, &event).await {
+            log::error!("error while archiving edited message: {}", error);
+        }
+    }
+
+    async fn message_delete(
+        &self,
+        ctx: Context,
+        channel_id: ChannelId,
+        deleted_message_id: MessageId,
+        guild_id: Option<GuildId>,
+    ) {
+        if let Err(error) =
+            on_message_change::message_delete(&ctx, channel_id, deleted_message_id, guild_id).await
+        {
+            log::error!("error while marking message as deleted: {}", error);
+        }
+    }
+
+    async fn message_delete_bulk(
+        &self,
+        ctx: Context,
+        channel_id: ChannelId,
+        multiple_deleted_messages_ids: Vec<MessageId>,
+        guild_id: Option<GuildId>,
+    ) {
+        for deleted_message_id in multiple_deleted_messages_ids {
+            if let Err(error) =
+                on_message_change::message_delete(&ctx, channel_id, deleted_message_id, guild_id)
+                    .await
+            {
+                log::error!("error while marking message as deleted: {}", error);
+            }
+        }
     }
 }
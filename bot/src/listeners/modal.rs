@@ -0,0 +1,49 @@
+//! Reading the modals opened from the buttons on report views, which are all
+//! submitted with the ID of their report.
+
+use serenity::{
+    client::Context,
+    model::application::{
+        component::ActionRowComponent,
+        interaction::{modal::ModalSubmitInteraction, InteractionResponseType},
+    },
+};
+
+use super::on_interaction::InteractionError;
+
+/// Reads the ID of the report a modal was opened for, if it's the kind of
+/// modal expected.
+pub fn report_id(modal: &ModalSubmitInteraction, kind: &str) -> Option<u64> {
+    (modal.data.custom_id.split_once(':'))
+        .filter(|(modal_kind, _)| *modal_kind == kind)
+        .and_then(|(_, id)| id.parse::<u64>().ok())
+}
+
+/// Reads what was written in a text input of a modal, which is empty if it was
+/// left blank.
+pub fn input<'a>(modal: &'a ModalSubmitInteraction, custom_id: &str) -> &'a str {
+    (modal.data.components.iter())
+        .flat_map(|row| row.components.iter())
+        .find_map(|component| match component {
+            ActionRowComponent::InputText(input) if input.custom_id == custom_id => {
+                Some(input.value.trim())
+            }
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+pub async fn reply(
+    ctx: &Context,
+    modal: &ModalSubmitInteraction,
+    content: &str,
+) -> Result<(), InteractionError> {
+    modal
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| d.content(content).ephemeral(true))
+        })
+        .await?;
+
+    Ok(())
+}
@@ -0,0 +1,117 @@
+//! Lets moderators write notes on a report in a modal, opened from the button
+//! on its mod view.
+
+use serenity::{
+    client::Context,
+    model::application::{
+        component::InputTextStyle,
+        interaction::{
+            message_component::MessageComponentInteraction, modal::ModalSubmitInteraction,
+            InteractionResponseType,
+        },
+    },
+};
+
+use super::{modal, on_interaction::InteractionError};
+use crate::{
+    database::{models::ViewModel, Database},
+    services::{self, NOTE_MAX_LENGTH},
+    view::UpdateViewError,
+};
+
+pub const NOTE_MODAL: &str = "mod_view_note";
+const NOTE_INPUT: &str = "note";
+
+pub async fn open(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), InteractionError> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let view = match (db.load_view_by_message(&component.message.id, &component.channel_id)).await?
+    {
+        Some(ViewModel::Mod(model)) => model,
+        _ => return Ok(()),
+    };
+
+    component
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::Modal)
+                .interaction_response_data(|d| {
+                    d.custom_id(format!("{}:{}", NOTE_MODAL, view.report_id))
+                        .title(format!("Note on Report #{}", view.report_id))
+                        .components(|c| {
+                            c.create_action_row(|row| {
+                                row.create_input_text(|i| {
+                                    i.custom_id(NOTE_INPUT)
+                                        .label("Note")
+                                        .placeholder("Only moderators can see this note")
+                                        .style(InputTextStyle::Paragraph)
+                                        .max_length(NOTE_MAX_LENGTH as u64)
+                                        .required(true)
+                                })
+                            })
+                        })
+                })
+        })
+        .await?;
+
+    Ok(())
+}
+
+pub async fn submit(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), InteractionError> {
+    let report_id = match modal::report_id(modal, NOTE_MODAL) {
+        Some(report_id) => report_id,
+        None => return Ok(()),
+    };
+
+    let note = modal::input(modal, NOTE_INPUT);
+
+    if note.is_empty() {
+        return modal::reply(ctx, modal, "❌ Your note can't be empty").await;
+    }
+
+    if note.chars().count() > NOTE_MAX_LENGTH {
+        let content = format!(
+            "❌ Your note can't be longer than {} characters",
+            NOTE_MAX_LENGTH
+        );
+        return modal::reply(ctx, modal, &content).await;
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .ok_or(UpdateViewError::ReportDoesntExist)?;
+
+    // don't trust that the custom ID hasn't been tampered with
+    if modal.guild_id != Some(report.guild_id) {
+        return modal::reply(ctx, modal, "❌ That report isn't from this server").await;
+    }
+
+    // updating the mod view takes a while, and we only have a few seconds to
+    // respond before the interaction fails
+    modal
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|d| d.ephemeral(true))
+        })
+        .await?;
+
+    let result = services::add_report_note(ctx, db, report.id, modal.user.id, note).await;
+
+    let content = match &result {
+        Ok(_) => "✅ Your note has been added".to_owned(),
+        Err(error) => format!("❌ Your note couldn't be added: {}", error),
+    };
+
+    modal
+        .edit_original_interaction_response(ctx, |r| r.content(content))
+        .await?;
+
+    Ok(result?)
+}
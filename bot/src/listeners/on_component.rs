@@ -0,0 +1,219 @@
+//! Handles moderators pressing the buttons on a mod view or a burst view.
+
+use serenity::{
+    client::Context,
+    model::{
+        application::interaction::{
+            message_component::MessageComponentInteraction, InteractionResponseType,
+        },
+        Permissions,
+    },
+    prelude::Mentionable,
+};
+
+use super::on_interaction::InteractionError;
+use crate::{
+    database::{
+        models::{ReportModel, ViewModel},
+        Database, MakeReportEffect,
+    },
+    services::{self, MakeReportError},
+    view::{self, UpdateViewError},
+};
+
+pub async fn mod_view_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), InteractionError> {
+    let custom_id = component.data.custom_id.as_str();
+
+    if ![
+        view::REFRESH_BUTTON,
+        view::CLAIM_BUTTON,
+        view::UNCLAIM_BUTTON,
+        view::ACCEPT_BUTTON,
+        view::DENY_BUTTON,
+        view::ACTION_SELECT,
+    ]
+    .contains(&custom_id)
+    {
+        return Ok(());
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let view = match (db.load_view_by_message(&component.message.id, &component.channel_id)).await?
+    {
+        Some(ViewModel::Mod(model)) => model,
+        _ => return Ok(()),
+    };
+
+    let report = db
+        .load_report(view.report_id)
+        .await?
+        .ok_or(UpdateViewError::ReportDoesntExist)?;
+
+    let moderator = component.user.id;
+
+    if custom_id == view::UNCLAIM_BUTTON && view.handler != Some(moderator) {
+        let content = match view.handler {
+            Some(handler) => format!("Only {} can unclaim this report", handler.mention()),
+            None => "This report hasn't been claimed".to_owned(),
+        };
+
+        component
+            .create_interaction_response(ctx, |r| {
+                r.kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|d| d.content(content).ephemeral(true))
+            })
+            .await?;
+
+        return Ok(());
+    }
+
+    // the bot takes the action, so the moderator must be allowed to take it,
+    // and handling a report is the same as for the buttons of a burst view
+    let permissions = match custom_id {
+        view::REFRESH_BUTTON => None,
+        view::ACTION_SELECT => (component.data.values.first())
+            .and_then(|v| view::parse_action_value(v))
+            .map(|a| a.kind().permissions())
+            .or(Some(Permissions::MANAGE_MESSAGES)),
+        _ => Some(Permissions::MANAGE_MESSAGES),
+    };
+
+    if let Some(permissions) = permissions {
+        if !has_permissions(ctx, component, permissions).await? {
+            return Ok(());
+        }
+    }
+
+    // the mod view is edited once we've handled the button
+    component
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredUpdateMessage)
+        })
+        .await?;
+
+    match custom_id {
+        view::CLAIM_BUTTON => services::claim_report(ctx, db, &report, moderator, true).await?,
+        view::UNCLAIM_BUTTON => services::claim_report(ctx, db, &report, moderator, false).await?,
+        view::ACCEPT_BUTTON => services::finalize_report(ctx, db, &report, moderator, true).await?,
+        view::DENY_BUTTON => services::finalize_report(ctx, db, &report, moderator, false).await?,
+        view::ACTION_SELECT => take_action(ctx, component, db, &report).await?,
+        _ => view::update_report_view(ctx, db, MakeReportEffect::Updated(report.id)).await?,
+    };
+
+    Ok(())
+}
+
+/// Accepts the report with the moderation action chosen from the action menu,
+/// letting the moderator know if the action couldn't be taken.
+async fn take_action(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    db: &Database,
+    report: &ReportModel,
+) -> Result<(), InteractionError> {
+    let action = (component.data.values.first()).and_then(|v| view::parse_action_value(v));
+
+    let error = match action {
+        Some(action) => {
+            services::accept_report_with_action(ctx, db, report, component.user.id, action)
+                .await
+                .err()
+        }
+        None => Some(MakeReportError::ActionNotAllowed),
+    };
+
+    if let Some(error) = error {
+        component
+            .create_followup_message(ctx, |m| {
+                m.content(format!("Couldn't take that action: {}", error))
+                    .ephemeral(true)
+            })
+            .await?;
+
+        // redrawing the view resets the action menu
+        view::update_report_view(ctx, db, MakeReportEffect::Updated(report.id)).await?;
+    }
+
+    Ok(())
+}
+
+pub async fn burst_view_button(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    action: &str,
+    burst_id: &str,
+) -> Result<(), InteractionError> {
+    let burst_id = match burst_id.parse::<u64>() {
+        Ok(burst_id) => burst_id,
+        Err(_) => return Ok(()),
+    };
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let burst = match db.load_burst(burst_id).await? {
+        Some(burst) => burst,
+        None => return Ok(()),
+    };
+
+    let finalizes = action == view::BURST_ACCEPT_BUTTON || action == view::BURST_DENY_BUTTON;
+    if finalizes && !has_permissions(ctx, component, Permissions::MANAGE_MESSAGES).await? {
+        return Ok(());
+    }
+
+    // the burst view is edited once we've handled the button
+    component
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredUpdateMessage)
+        })
+        .await?;
+
+    let moderator = component.user.id;
+
+    match action {
+        view::BURST_ACCEPT_BUTTON => {
+            services::finalize_burst(ctx, db, burst.id, moderator, true).await?
+        }
+        view::BURST_DENY_BUTTON => {
+            services::finalize_burst(ctx, db, burst.id, moderator, false).await?
+        }
+        _ => view::update_burst_view(ctx, db, &burst).await?,
+    };
+
+    Ok(())
+}
+
+/// Checks that the member pressing a button has the permissions it needs,
+/// letting them know if they don't. The bot acts with its own permissions, so
+/// anyone who can see a view could otherwise act through it.
+async fn has_permissions(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+    permissions: Permissions,
+) -> Result<bool, InteractionError> {
+    let allowed = (component.member.as_ref())
+        .and_then(|m| m.permissions)
+        .map_or(false, |p| p.contains(permissions));
+
+    if !allowed {
+        component
+            .create_interaction_response(ctx, |r| {
+                r.kind(InteractionResponseType::ChannelMessageWithSource)
+                    .interaction_response_data(|d| {
+                        d.content(format!(
+                            "You need the {} permission to do that",
+                            permissions
+                        ))
+                        .ephemeral(true)
+                    })
+            })
+            .await?;
+    }
+
+    Ok(allowed)
+}
@@ -0,0 +1,194 @@
+use serenity::{
+    client::Context,
+    model::{
+        application::interaction::{
+            application_command::{
+                ApplicationCommandInteraction, CommandDataOptionValue, ResolvedTarget,
+            },
+            Interaction, InteractionResponseType,
+        },
+        id::{ChannelId, MessageId, UserId},
+    },
+};
+use thiserror::Error;
+
+use super::{
+    application_commands::{REPORT_COMMAND, REPORT_MESSAGE_COMMAND, REPORT_USER_COMMAND},
+    note_modal::{self, NOTE_MODAL},
+    on_component,
+    reason_modal::{self, REASON_MODAL},
+    warning_modal::{self, WARNING_MODAL},
+};
+use crate::{
+    database::Database,
+    services::{self, MakeReportError},
+    view::{
+        UpdateViewError, BURST_ACCEPT_BUTTON, BURST_DENY_BUTTON, BURST_REFRESH_BUTTON,
+        EDIT_REASON_BUTTON, NOTE_BUTTON, WARNING_BUTTON,
+    },
+};
+
+#[derive(Debug, Error)]
+pub enum InteractionError {
+    #[error("An SQL error occurred: {0}")]
+    Sql(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    Discord(#[from] serenity::Error),
+    #[error("An error occurred while making the report: {0}")]
+    MakeReport(#[from] MakeReportError),
+    #[error("An error occurred while updating the view: {0}")]
+    ViewUpdate(#[from] UpdateViewError),
+}
+
+pub async fn interaction_create(
+    ctx: &Context,
+    interaction: &Interaction,
+) -> Result<(), InteractionError> {
+    match interaction {
+        Interaction::ApplicationCommand(command) => handle_command(ctx, command).await,
+        Interaction::MessageComponent(component) => match component.data.custom_id.as_str() {
+            EDIT_REASON_BUTTON => reason_modal::open(ctx, component).await,
+            NOTE_BUTTON => note_modal::open(ctx, component).await,
+            WARNING_BUTTON => warning_modal::open(ctx, component).await,
+            custom_id => match custom_id.split_once(':') {
+                Some((
+                    action @ (BURST_REFRESH_BUTTON | BURST_ACCEPT_BUTTON | BURST_DENY_BUTTON),
+                    burst_id,
+                )) => on_component::burst_view_button(ctx, component, action, burst_id).await,
+                _ => on_component::mod_view_button(ctx, component).await,
+            },
+        },
+        Interaction::ModalSubmit(modal) => match modal.data.custom_id.split_once(':') {
+            Some((REASON_MODAL, _)) => reason_modal::submit(ctx, modal).await,
+            Some((NOTE_MODAL, _)) => note_modal::submit(ctx, modal).await,
+            Some((WARNING_MODAL, _)) => warning_modal::submit(ctx, modal).await,
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Everything a report command needs to know about what is being reported.
+struct ReportTarget {
+    reported_user_id: UserId,
+    reported_message: Option<(ChannelId, MessageId)>,
+    reason: Option<String>,
+    category_id: Option<u64>,
+}
+
+async fn handle_command(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+) -> Result<(), InteractionError> {
+    let target = match command.data.name.as_str() {
+        REPORT_COMMAND => report_command_target(command),
+        REPORT_MESSAGE_COMMAND | REPORT_USER_COMMAND => match command.data.target() {
+            Some(ResolvedTarget::Message(message)) => Some(ReportTarget {
+                reported_user_id: message.author.id,
+                reported_message: Some((message.channel_id, message.id)),
+                reason: None,
+                category_id: None,
+            }),
+            Some(ResolvedTarget::User(user, _)) => Some(ReportTarget {
+                reported_user_id: user.id,
+                reported_message: None,
+                reason: None,
+                category_id: None,
+            }),
+            _ => None,
+        },
+        _ => return Ok(()),
+    };
+
+    let guild_id = match command.guild_id {
+        Some(guild_id) => guild_id,
+        None => return reply(ctx, command, "❌ Reports can only be made within a server").await,
+    };
+
+    let target = match target {
+        Some(target) => target,
+        None => return reply(ctx, command, "❌ Couldn't find what you are reporting").await,
+    };
+
+    // making a report takes a while, and we only have a few seconds to respond
+    // before the interaction fails
+    command
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|d| d.ephemeral(true))
+        })
+        .await?;
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let result = services::make_report(
+        ctx,
+        db,
+        guild_id,
+        command.user.id,
+        target.reported_user_id,
+        target.reported_message,
+        target.reason.as_deref(),
+        target.category_id,
+    )
+    .await;
+
+    let content = match &result {
+        Ok(Some(_)) => {
+            "✅ Your report has been received. Check your DMs for its status.".to_owned()
+        }
+        // the reporter was already told why in their DMs
+        Ok(None) => "Your report wasn't filed. Check your DMs for details.".to_owned(),
+        Err(error) => format!("❌ Your report couldn't be submitted: {}", error),
+    };
+
+    command
+        .edit_original_interaction_response(ctx, |r| r.content(content))
+        .await?;
+
+    result?;
+
+    Ok(())
+}
+
+fn report_command_target(command: &ApplicationCommandInteraction) -> Option<ReportTarget> {
+    let mut reported_user_id = None;
+    let mut reason = None;
+    let mut category_id = None;
+
+    for option in &command.data.options {
+        match (option.name.as_str(), &option.resolved) {
+            ("user", Some(CommandDataOptionValue::User(user, _))) => {
+                reported_user_id = Some(user.id)
+            }
+            ("reason", Some(CommandDataOptionValue::String(text))) => reason = Some(text.clone()),
+            ("category", Some(CommandDataOptionValue::String(id))) => {
+                category_id = id.parse::<u64>().ok()
+            }
+            _ => {}
+        }
+    }
+
+    Some(ReportTarget {
+        reported_user_id: reported_user_id?,
+        reported_message: None,
+        reason,
+        category_id,
+    })
+}
+
+async fn reply(
+    ctx: &Context,
+    command: &ApplicationCommandInteraction,
+    content: &str,
+) -> Result<(), InteractionError> {
+    command
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::ChannelMessageWithSource)
+                .interaction_response_data(|d| d.content(content).ephemeral(true))
+        })
+        .await?;
+
+    Ok(())
+}
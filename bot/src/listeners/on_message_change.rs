@@ -0,0 +1,110 @@
+//! Keeps the archive of reported messages up to date as they are edited or
+//! deleted, so moderators can see what happened after the report was made.
+
+use serenity::{
+    client::Context,
+    model::{
+        channel::Message,
+        event::MessageUpdateEvent,
+        id::{ChannelId, GuildId, MessageId},
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    database::{ArchiveMessageEffect, Database, MakeReportEffect},
+    services,
+    view::{self, UpdateViewError},
+};
+
+#[derive(Debug, Error)]
+pub enum MessageChangeError {
+    #[error("An SQL error occurred: {0}")]
+    Sql(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    Discord(#[from] serenity::Error),
+    #[error("An error occurred while updating the view: {0}")]
+    ViewUpdate(#[from] UpdateViewError),
+}
+
+pub async fn message_update(
+    ctx: &Context,
+    new: Option<Message>,
+    event: &MessageUpdateEvent,
+) -> Result<(), MessageChangeError> {
+    let guild_id = match event.guild_id {
+        Some(guild_id) => guild_id,
+        // messages in DMs can't be reported
+        None => return Ok(()),
+    };
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    // nearly every edit is to a message that was never reported, so check that
+    // before we bother fetching the message
+    let reports = db.load_open_reports_by_message(&event.id).await?;
+    if reports.is_empty() {
+        return Ok(());
+    }
+
+    let message = match new {
+        Some(message) => message,
+        None => event.channel_id.message(ctx, event.id).await?,
+    };
+
+    archive_revision(ctx, db, guild_id, &message, reports).await
+}
+
+async fn archive_revision(
+    ctx: &Context,
+    db: &Database,
+    guild_id: GuildId,
+    message: &Message,
+    reports: Vec<u64>,
+) -> Result<(), MessageChangeError> {
+    let attachments = services::archive_attachments(db, guild_id, message).await?;
+
+    // discord sends updates for things like embeds loading, which we don't
+    // consider to be a new revision
+    if let ArchiveMessageEffect::None(_) = db.archive_message(message, &attachments).await? {
+        return Ok(());
+    }
+
+    refresh_reports(ctx, db, reports).await
+}
+
+pub async fn message_delete(
+    ctx: &Context,
+    _channel_id: ChannelId,
+    message_id: MessageId,
+    guild_id: Option<GuildId>,
+) -> Result<(), MessageChangeError> {
+    if guild_id.is_none() {
+        return Ok(());
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let reports = db.load_open_reports_by_message(&message_id).await?;
+    if reports.is_empty() {
+        return Ok(());
+    }
+
+    db.mark_message_deleted(message_id).await?;
+
+    refresh_reports(ctx, db, reports).await
+}
+
+async fn refresh_reports(
+    ctx: &Context,
+    db: &Database,
+    reports: Vec<u64>,
+) -> Result<(), MessageChangeError> {
+    for report_id in reports {
+        view::update_report_view(ctx, db, MakeReportEffect::Updated(report_id)).await?;
+    }
+
+    Ok(())
+}
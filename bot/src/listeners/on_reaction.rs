@@ -15,17 +15,15 @@ use crate::{
 #[derive(Debug, Error)]
 pub enum ReactionAddError {
     #[error("An SQL error occurred: {0}")]
-    SqlError(#[from] sqlx::Error),
+    Sql(#[from] sqlx::Error),
     #[error("A Discord error occurred: {0}")]
-    DiscordError(#[from] serenity::Error),
+    Discord(#[from] serenity::Error),
     #[error("An error occurred while making the report: {0}")]
-    MakeReportError(#[from] MakeReportError),
+    MakeReport(#[from] MakeReportError),
     #[error("An error occurred while updating the view: {0}")]
-    ViewUpdateError(#[from] UpdateViewError),
+    ViewUpdate(#[from] UpdateViewError),
     #[error("An error occurred while updating the report: {0}")]
-    ReportUpdateError(#[from] ReportUpdateError),
-    #[error("Timed out")]
-    TimedOut,
+    ReportUpdate(#[from] ReportUpdateError),
 }
 
 pub async fn reaction_add(ctx: &Context, reaction: &Reaction) -> Result<(), ReactionAddError> {
@@ -53,12 +51,14 @@ pub async fn reaction_add(ctx: &Context, reaction: &Reaction) -> Result<(), Reac
 
     if is_report_emoji(&reaction, &db).await? {
         handle_report(&ctx, &reaction, &db).await?;
-    } else if is_refresh_emoji(&reaction.emoji) {
-        handle_refresh(&ctx, &reaction, &db).await?;
     } else if is_edit_emoji(&reaction.emoji) {
         handle_edit(&ctx, &reaction, &db).await?;
+    } else if !uses_reaction_controls(reaction, db).await? {
+        // the mod view is controlled with buttons instead
+    } else if is_refresh_emoji(&reaction.emoji) {
+        handle_refresh(&ctx, &reaction, &db).await?;
     } else if is_claim_emoji(&reaction.emoji) {
-        handle_claim(&ctx, &reaction, &db).await?;
+        handle_claim(&ctx, &reaction, user_id, &db).await?;
     } else if is_accept_emoji(&reaction.emoji) {
         handle_finalize(&ctx, &reaction, user_id, &db, true).await?;
     } else if is_reject_emoji(&reaction.emoji) {
@@ -85,8 +85,8 @@ pub async fn reaction_removed(ctx: &Context, reaction: &Reaction) -> Result<(),
     let data = ctx.data.read().await;
     let db = data.get::<Database>().unwrap();
 
-    if is_claim_emoji(&reaction.emoji) {
-        handle_claim(&ctx, &reaction, &db).await?;
+    if is_claim_emoji(&reaction.emoji) && uses_reaction_controls(reaction, db).await? {
+        handle_claim(&ctx, &reaction, user_id, &db).await?;
     }
 
     Ok(())
@@ -105,15 +105,19 @@ async fn handle_report(
     let reported_message = reaction.message(ctx).await?;
     let user_reporting = reaction.user(&ctx).await?;
 
+    // each category has its own report emoji
+    let category_id = (db.get_server_config(&guild_id).await?)
+        .and_then(|config| config.category_for_emoji(&reaction.emoji).map(|c| c.id));
+
     services::make_report(
         &ctx,
         &db,
         guild_id,
         user_reporting.id,
         reported_message.author.id,
-        Some(reaction.channel_id),
-        Some(reaction.message_id),
+        Some((reaction.channel_id, reaction.message_id)),
         None,
+        category_id,
     )
     .await?;
 
@@ -162,22 +166,15 @@ async fn handle_edit(
         reaction.delete(&ctx).await?;
     }
 
-    let user = reaction.user(&ctx).await?;
-
-    let msg = reaction
-        .channel_id
-        .send_message(&ctx, |m| m.content("Type the reason for your report:"))
-        .await?;
-
-    let reasoning = crate::serenity_utils::prompt::message_prompt_content(&ctx, &msg, &user, 30.0)
-        .await
-        .ok_or(ReactionAddError::TimedOut)?;
-
-    services::update_report_reason(&ctx, &db, view.report_id, reasoning).await?;
+    // reasons are written in a modal, which can only be opened by pressing a
+    // button. older reports may not have the button yet, so add it
+    view::update_report_view(ctx, db, MakeReportEffect::Updated(view.report_id)).await?;
 
     reaction
         .channel_id
-        .send_message(&ctx, |m| m.content("✅ Your report has been updated"))
+        .send_message(&ctx, |m| {
+            m.content("Press the 📝 Edit Reason button on your report to provide a reason")
+        })
         .await?;
 
     Ok(())
@@ -186,6 +183,7 @@ async fn handle_edit(
 async fn handle_claim(
     ctx: &Context,
     reaction: &Reaction,
+    reaction_user: UserId,
     db: &Database,
 ) -> Result<(), ReactionAddError> {
     let view = db
@@ -203,7 +201,7 @@ async fn handle_claim(
     let report = match db.load_report(view.report_id).await? {
         Some(r) => r,
         None => {
-            return Err(ReactionAddError::ViewUpdateError(
+            return Err(ReactionAddError::ViewUpdate(
                 UpdateViewError::ReportDoesntExist,
             ))
         }
@@ -231,7 +229,7 @@ async fn handle_claim(
         x => panic!("x = {}: x >= 2 == false, x < 2 == false, wtf?", x),
     };
 
-    services::update_report_status(&ctx, &db, report.id, new_status).await?;
+    services::update_report_status(&ctx, &db, report.id, reaction_user, new_status).await?;
 
     Ok(())
 }
@@ -258,7 +256,7 @@ async fn handle_finalize(
     let report = match db.load_report(view.report_id).await? {
         Some(r) => r,
         None => {
-            return Err(ReactionAddError::ViewUpdateError(
+            return Err(ReactionAddError::ViewUpdate(
                 UpdateViewError::ReportDoesntExist,
             ))
         }
@@ -266,30 +264,13 @@ async fn handle_finalize(
 
     reaction.delete(&ctx).await?;
 
-    let new_status = match is_accepted {
-        true => ReportStatus::Accepted,
-        false => ReportStatus::Denied,
-    };
-
-    db.update_mod_view_handler(report.id, reaction_user).await?;
-    services::update_report_status(&ctx, &db, report.id, new_status).await?;
-
-    if report.status != new_status {
-        if let Some(user_model) = db.load_user_view(report.id).await? {
-            let dms = report.accuser_user_id.create_dm_channel(&ctx).await?;
-
-            dms.send_message(&ctx, |m| {
-                m.content(format!(
-                    "Your report (#{}) has been {}!",
-                    report.id,
-                    new_status.into_human_status()
-                ))
-                .reference_message((dms.id, user_model.message_id))
-            })
-            .await?;
-        }
+    // resolved reports can't be accepted or denied again
+    if !report.status.is_open() {
+        return Ok(());
     }
 
+    services::finalize_report(ctx, db, &report, reaction_user, is_accepted).await?;
+
     Ok(())
 }
 
@@ -311,6 +292,20 @@ async fn is_report_emoji(reaction: &Reaction, db: &Database) -> Result<bool, sql
     ))
 }
 
+/// Checks if the server has opted to control mod views with reactions rather
+/// than buttons.
+async fn uses_reaction_controls(reaction: &Reaction, db: &Database) -> Result<bool, sqlx::Error> {
+    let guild_id = match reaction.guild_id {
+        Some(guild_id) => guild_id,
+        // mod views are never in DMs
+        None => return Ok(false),
+    };
+
+    let server_config = db.get_server_config(&guild_id).await?;
+
+    Ok(server_config.map_or(false, |c| c.mod_view_reactions))
+}
+
 fn matches_server_emoji(emoji: &ReactionType, server_config: Option<&ServerConfiguration>) -> bool {
     match server_config {
         Some(config) => config.matches_emoji(&emoji),
@@ -0,0 +1,132 @@
+//! Lets reporters write the reason for their report in a modal, opened from
+//! the button on their user view.
+
+use serenity::{
+    client::Context,
+    model::application::{
+        component::InputTextStyle,
+        interaction::{
+            message_component::MessageComponentInteraction, modal::ModalSubmitInteraction,
+            InteractionResponseType,
+        },
+    },
+};
+
+use super::{modal, on_interaction::InteractionError};
+use crate::{
+    database::{models::ViewModel, Database},
+    services::{self, REASON_MAX_LENGTH},
+    view::UpdateViewError,
+};
+
+pub const REASON_MODAL: &str = "user_view_reason";
+const REASON_INPUT: &str = "reason";
+
+pub async fn open(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), InteractionError> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let view = match (db.load_view_by_message(&component.message.id, &component.channel_id)).await?
+    {
+        Some(ViewModel::User(model)) => model,
+        _ => return Ok(()),
+    };
+
+    let report = db
+        .load_report(view.report_id)
+        .await?
+        .ok_or(UpdateViewError::ReportDoesntExist)?;
+
+    component
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::Modal)
+                .interaction_response_data(|d| {
+                    d.custom_id(format!("{}:{}", REASON_MODAL, report.id))
+                        .title(format!("Report #{}", report.id))
+                        .components(|c| {
+                            c.create_action_row(|row| {
+                                row.create_input_text(|i| {
+                                    i.custom_id(REASON_INPUT)
+                                        .label("Reason")
+                                        .placeholder(
+                                            "Describe what happened and why you are reporting it",
+                                        )
+                                        .style(InputTextStyle::Paragraph)
+                                        .max_length(REASON_MAX_LENGTH as u64)
+                                        .required(true);
+
+                                    if let Some(reason) = &report.reason {
+                                        i.value(reason);
+                                    }
+
+                                    i
+                                })
+                            })
+                        })
+                })
+        })
+        .await?;
+
+    Ok(())
+}
+
+pub async fn submit(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), InteractionError> {
+    let report_id = match modal::report_id(modal, REASON_MODAL) {
+        Some(report_id) => report_id,
+        None => return Ok(()),
+    };
+
+    let reason = modal::input(modal, REASON_INPUT);
+
+    if reason.is_empty() {
+        return modal::reply(ctx, modal, "❌ Your reason can't be empty").await;
+    }
+
+    if reason.chars().count() > REASON_MAX_LENGTH {
+        let content = format!(
+            "❌ Your reason can't be longer than {} characters",
+            REASON_MAX_LENGTH
+        );
+        return modal::reply(ctx, modal, &content).await;
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .ok_or(UpdateViewError::ReportDoesntExist)?;
+
+    // the modal can only be opened from the reporter's DMs, but don't trust
+    // that the custom ID hasn't been tampered with
+    if report.accuser_user_id != modal.user.id {
+        return modal::reply(ctx, modal, "❌ You can only edit your own reports").await;
+    }
+
+    // updating the report takes a while, and we only have a few seconds to
+    // respond before the interaction fails
+    modal
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|d| d.ephemeral(true))
+        })
+        .await?;
+
+    let result =
+        services::update_report_reason(ctx, db, report.id, modal.user.id, reason.to_owned()).await;
+
+    let content = match &result {
+        Ok(_) => "✅ Your report has been updated".to_owned(),
+        Err(error) => format!("❌ Your report couldn't be updated: {}", error),
+    };
+
+    modal
+        .edit_original_interaction_response(ctx, |r| r.content(content))
+        .await?;
+
+    Ok(result?)
+}
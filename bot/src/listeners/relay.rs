@@ -0,0 +1,148 @@
+//! Relays messages between the moderators and the person that made a report.
+//! Moderators answer a reporter by talking in the report's thread, or by
+//! replying to their relayed message, and reporters answer by replying in
+//! their DMs to a message from the moderators, or to their view of the report.
+
+use serenity::{
+    client::Context,
+    model::{
+        channel::{Message, ReactionType},
+        id::{ChannelId, MessageId},
+    },
+};
+use thiserror::Error;
+
+use crate::{
+    commands,
+    database::{models::ViewModel, Database},
+    services::{self, MakeReportError, RELAY_MAX_LENGTH},
+    view,
+};
+
+#[derive(Debug, Error)]
+pub enum RelayError {
+    #[error("Message was not sent from within a guild")]
+    NoGuild,
+    #[error("This server has not been configured yet - run `n!setup` first")]
+    UnconfiguredServer,
+    #[error("Expected either `anonymous` or `named`")]
+    InvalidArguments,
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
+    #[error("An error occurred while relaying the message: {0}")]
+    MakeReportError(#[from] MakeReportError),
+}
+
+pub async fn message(ctx: &Context, message: &Message) -> Result<(), RelayError> {
+    if message.author.bot {
+        return Ok(());
+    }
+
+    // reporters can only answer by replying
+    if message.guild_id.is_none() && replied_to(message).is_none() {
+        return Ok(());
+    }
+
+    let content = relayed_content(message);
+    if content.is_empty() {
+        return Ok(());
+    }
+
+    // commands aren't meant for the reporter
+    if message.guild_id.is_some() {
+        let prefix = commands::dynamic_prefix(ctx, message).await;
+        if prefix.map_or(false, |p| message.content.starts_with(&p)) {
+            return Ok(());
+        }
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report_id = match message.guild_id {
+        Some(_) => moderator_report(db, message).await?,
+        None => reporter_report(db, message).await?,
+    };
+
+    let report = match report_id {
+        Some(report_id) => match db.load_report(report_id).await? {
+            Some(report) => report,
+            None => return Ok(()),
+        },
+        None => return Ok(()),
+    };
+
+    match message.guild_id {
+        Some(guild_id) if guild_id == report.guild_id => {
+            services::relay_to_reporter(ctx, db, &report, &message.author, &content).await?
+        }
+        None if message.author.id == report.accuser_user_id => {
+            services::relay_to_moderators(ctx, db, &report, &message.author, &content).await?
+        }
+        _ => return Ok(()),
+    };
+
+    message
+        .react(ctx, ReactionType::Unicode("✅".to_owned()))
+        .await?;
+
+    Ok(())
+}
+
+/// Finds the report a moderator is answering, either through the relayed
+/// message they replied to or the report thread they're talking in. Anyone
+/// who can do either can already see the report.
+async fn moderator_report(db: &Database, message: &Message) -> Result<Option<u64>, sqlx::Error> {
+    if let Some((channel_id, message_id)) = replied_to(message) {
+        match db.load_relay_message(&channel_id, &message_id).await? {
+            Some(relay) if !relay.to_reporter => return Ok(Some(relay.report_id)),
+            _ => {}
+        }
+    }
+
+    let view = db.load_mod_view_by_thread(&message.channel_id).await?;
+
+    Ok(view.map(|v| v.report_id))
+}
+
+/// Finds the report a reporter is answering, through the message from the
+/// moderators or the view of the report they replied to.
+async fn reporter_report(db: &Database, message: &Message) -> Result<Option<u64>, sqlx::Error> {
+    let (channel_id, message_id) = match replied_to(message) {
+        Some(reference) => reference,
+        None => return Ok(None),
+    };
+
+    Ok(
+        match db.load_relay_message(&channel_id, &message_id).await? {
+            Some(relay) if relay.to_reporter => Some(relay.report_id),
+            Some(_) => None,
+            None => match db.load_view_by_message(&message_id, &channel_id).await? {
+                Some(ViewModel::User(view)) => Some(view.report_id),
+                _ => None,
+            },
+        },
+    )
+}
+
+fn replied_to(message: &Message) -> Option<(ChannelId, MessageId)> {
+    let reference = message.message_reference.as_ref()?;
+    reference.message_id.map(|id| (reference.channel_id, id))
+}
+
+/// The text of a message, followed by links to its attachments.
+fn relayed_content(message: &Message) -> String {
+    let mut lines = Vec::with_capacity(message.attachments.len() + 1);
+
+    if !message.content.trim().is_empty() {
+        lines.push(message.content.trim().to_owned());
+    }
+
+    for attachment in &message.attachments {
+        lines.push(format!("📎 [{}]({})", attachment.filename, attachment.url));
+    }
+
+    view::truncate(&lines.join("\n"), RELAY_MAX_LENGTH)
+}
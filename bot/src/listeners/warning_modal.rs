@@ -0,0 +1,152 @@
+//! Lets moderators write the warning a reported user is sent once the report
+//! against them is accepted, opened from the button on its mod view.
+
+use serenity::{
+    client::Context,
+    model::application::{
+        component::InputTextStyle,
+        interaction::{
+            message_component::MessageComponentInteraction, modal::ModalSubmitInteraction,
+            InteractionResponseType,
+        },
+    },
+};
+
+use super::{modal, on_interaction::InteractionError};
+use crate::{
+    database::{models::ViewModel, Database, MakeReportEffect},
+    services::NOTE_MAX_LENGTH,
+    view::{self, UpdateViewError},
+};
+
+pub const WARNING_MODAL: &str = "mod_view_warning";
+const RULE_INPUT: &str = "rule";
+const NOTE_INPUT: &str = "note";
+
+/// The most characters the rule a reported user broke may have.
+const RULE_MAX_LENGTH: usize = 200;
+
+pub async fn open(
+    ctx: &Context,
+    component: &MessageComponentInteraction,
+) -> Result<(), InteractionError> {
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let view = match (db.load_view_by_message(&component.message.id, &component.channel_id)).await?
+    {
+        Some(ViewModel::Mod(model)) => model,
+        _ => return Ok(()),
+    };
+
+    let warning = db.load_report_warning(view.report_id).await?;
+    let rule = (warning.as_ref()).and_then(|w| w.rule.clone());
+    let note = (warning.as_ref()).and_then(|w| w.note.clone());
+
+    component
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::Modal)
+                .interaction_response_data(|d| {
+                    d.custom_id(format!("{}:{}", WARNING_MODAL, view.report_id))
+                        .title(format!("Warning for Report #{}", view.report_id))
+                        .components(|c| {
+                            c.create_action_row(|row| {
+                                row.create_input_text(|i| {
+                                    i.custom_id(RULE_INPUT)
+                                        .label("Rule Broken")
+                                        .style(InputTextStyle::Short)
+                                        .max_length(RULE_MAX_LENGTH as u64)
+                                        .required(false);
+
+                                    // discord rejects empty values
+                                    if let Some(rule) = rule.filter(|r| !r.is_empty()) {
+                                        i.value(rule);
+                                    }
+
+                                    i
+                                })
+                            })
+                            .create_action_row(|row| {
+                                row.create_input_text(|i| {
+                                    i.custom_id(NOTE_INPUT)
+                                        .label("Note")
+                                        .placeholder("The reported user will see this note")
+                                        .style(InputTextStyle::Paragraph)
+                                        .max_length(NOTE_MAX_LENGTH as u64)
+                                        .required(false);
+
+                                    if let Some(note) = note.filter(|n| !n.is_empty()) {
+                                        i.value(note);
+                                    }
+
+                                    i
+                                })
+                            })
+                        })
+                })
+        })
+        .await?;
+
+    Ok(())
+}
+
+pub async fn submit(ctx: &Context, modal: &ModalSubmitInteraction) -> Result<(), InteractionError> {
+    let report_id = match modal::report_id(modal, WARNING_MODAL) {
+        Some(report_id) => report_id,
+        None => return Ok(()),
+    };
+
+    let rule = Some(modal::input(modal, RULE_INPUT)).filter(|r| !r.is_empty());
+    let note = Some(modal::input(modal, NOTE_INPUT)).filter(|n| !n.is_empty());
+
+    if rule.map_or(0, |r| r.chars().count()) > RULE_MAX_LENGTH
+        || note.map_or(0, |n| n.chars().count()) > NOTE_MAX_LENGTH
+    {
+        return modal::reply(ctx, modal, "❌ Your warning is too long").await;
+    }
+
+    let data = ctx.data.read().await;
+    let db = data.get::<Database>().unwrap();
+
+    let report = db
+        .load_report(report_id)
+        .await?
+        .ok_or(UpdateViewError::ReportDoesntExist)?;
+
+    // don't trust that the custom ID hasn't been tampered with
+    if modal.guild_id != Some(report.guild_id) {
+        return modal::reply(ctx, modal, "❌ That report isn't from this server").await;
+    }
+
+    let warning = db.load_report_warning(report.id).await?;
+    if warning.and_then(|w| w.sent).is_some() {
+        return modal::reply(ctx, modal, "❌ The warning has already been sent").await;
+    }
+
+    // updating the mod view takes a while, and we only have a few seconds to
+    // respond before the interaction fails
+    modal
+        .create_interaction_response(ctx, |r| {
+            r.kind(InteractionResponseType::DeferredChannelMessageWithSource)
+                .interaction_response_data(|d| d.ephemeral(true))
+        })
+        .await?;
+
+    db.save_report_warning(report.id, modal.user.id, rule, note)
+        .await?;
+    let result = view::update_report_view(ctx, db, MakeReportEffect::Updated(report.id)).await;
+
+    let content = match &result {
+        Ok(_) => "✅ The warning will be sent once the report is accepted".to_owned(),
+        Err(error) => format!(
+            "❌ Your warning was saved, but the mod view couldn't be updated: {}",
+            error
+        ),
+    };
+
+    modal
+        .edit_original_interaction_response(ctx, |r| r.content(content))
+        .await?;
+
+    Ok(result?)
+}
@@ -53,6 +53,7 @@ async fn main() -> Result<()> {
         .on_dispatch_error(dispatch_error)
         .help(&HELP)
         .group(&ASSISTANCE_GROUP)
+        .group(&MODERATION_GROUP)
         .group(&ADMINISTRATION_GROUP);
 
     let intents = GatewayIntents::all();
@@ -0,0 +1,39 @@
+use std::time::Duration;
+
+/// Parses a duration such as `30m`, `12h`, `7d` or `1w2d`. Returns `None` if
+/// the text isn't a duration.
+pub fn duration(text: &str) -> Option<Duration> {
+    let mut seconds = 0u64;
+    let mut amount = None::<u64>;
+
+    for c in text.chars() {
+        match c.to_digit(10) {
+            Some(digit) => {
+                amount = Some(
+                    amount
+                        .unwrap_or(0)
+                        .checked_mul(10)?
+                        .checked_add(digit as u64)?,
+                );
+            }
+            None => {
+                let unit = match c.to_ascii_lowercase() {
+                    's' => 1,
+                    'm' => 60,
+                    'h' => 60 * 60,
+                    'd' => 60 * 60 * 24,
+                    'w' => 60 * 60 * 24 * 7,
+                    _ => return None,
+                };
+
+                seconds = seconds.checked_add(amount.take()?.checked_mul(unit)?)?;
+            }
+        }
+    }
+
+    // every amount needs a unit, and there needs to be at least one amount
+    match (amount, seconds) {
+        (None, seconds) if seconds > 0 => Some(Duration::from_secs(seconds)),
+        _ => None,
+    }
+}
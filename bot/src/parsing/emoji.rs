@@ -0,0 +1,13 @@
+use serenity::{model::channel::ReactionType, utils::parse_emoji};
+
+/// Parses a unicode emoji, or a custom emoji by its mention.
+pub fn emoji(argument: &str) -> Option<ReactionType> {
+    if let Some(twemoji) = (argument.chars())
+        .take(1)
+        .find(|&c| unic::emoji::char::is_emoji(c))
+    {
+        return Some(ReactionType::Unicode(twemoji.to_string()));
+    }
+
+    parse_emoji(argument).map(|emoji| emoji.into())
+}
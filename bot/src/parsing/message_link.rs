@@ -0,0 +1,21 @@
+use lazy_regex::regex;
+#[allow(unused)] // `regex!` macro uses `lazy_static!`
+use lazy_static::lazy_static;
+use regex::Regex;
+use serenity::model::id::{ChannelId, GuildId, MessageId};
+
+/// Finds every link to a message in a server, such as the ones made by "Copy
+/// Message Link".
+pub fn message_links(msg: &str) -> Vec<(GuildId, ChannelId, MessageId)> {
+    // rust-analyzer intellisense
+    let regex: &Regex =
+        regex!(r"https://(?:(?:ptb|canary)\.)?discord(?:app)?\.com/channels/(\d+)/(\d+)/(\d+)");
+
+    regex
+        .captures_iter(msg)
+        .filter_map(|captures| {
+            let id = |idx: usize| captures.get(idx)?.as_str().parse::<u64>().ok();
+            Some((GuildId(id(1)?), ChannelId(id(2)?), MessageId(id(3)?)))
+        })
+        .collect()
+}
@@ -3,3 +3,15 @@ pub use user::*;
 
 mod channel_mention;
 pub use channel_mention::*;
+
+mod duration;
+pub use duration::*;
+
+mod role;
+pub use role::*;
+
+mod emoji;
+pub use emoji::*;
+
+mod message_link;
+pub use message_link::*;
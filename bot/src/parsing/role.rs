@@ -0,0 +1,23 @@
+use serenity::{
+    model::{guild::Guild, id::RoleId},
+    utils::parse_role,
+};
+
+/// Parses a role in the guild by its mention, ID or name. Returns `None` if
+/// the argument isn't a role, as it may be a user instead.
+pub fn role(argument: &str, guild: &Guild) -> Option<RoleId> {
+    // mentioning the role
+    if let Some(id) = parse_role(argument) {
+        return Some(RoleId(id));
+    }
+
+    // pasting the role id
+    if let Ok(id) = argument.parse::<u64>() {
+        if guild.roles.contains_key(&RoleId(id)) {
+            return Some(RoleId(id));
+        }
+    }
+
+    // by exact role name
+    guild.role_by_name(argument).map(|role| role.id)
+}
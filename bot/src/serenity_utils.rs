@@ -389,6 +389,166 @@ pub mod prompt {
     }
 }
 
+pub mod menu {
+    //! Paginated menus, which let a user flip through pages of embeds.
+    //!
+    //! Unlike the upstream crate, pages are flipped with buttons rather than
+    //! reactions, so the bot doesn't need permission to manage reactions.
+    //!
+    //! ## Example
+    //!
+    //! ```
+    //! # use serenity::{
+    //! #    builder::CreateEmbed,
+    //! #    model::prelude::Message,
+    //! #    prelude::Context,
+    //! # };
+    //! # use serenity_utils::{menu::{menu, MenuOptions}, Error};
+    //! #
+    //! async fn pages(ctx: &Context, msg: &Message) -> Result<(), Error> {
+    //!     let mut first = CreateEmbed::default();
+    //!     first.description("Dogs are great!");
+    //!
+    //!     let mut second = CreateEmbed::default();
+    //!     second.description("Cats are great too!");
+    //!
+    //!     menu(ctx, msg, &msg.author, &[first, second], MenuOptions::default()).await?;
+    //!
+    //!     Ok(())
+    //! }
+    //! ```
+
+    use crate::serenity_utils::error::Error;
+    use serenity::{
+        builder::{CreateComponents, CreateEmbed},
+        model::{
+            application::{component::ButtonStyle, interaction::InteractionResponseType},
+            prelude::{Message, ReactionType, User},
+        },
+        prelude::Context,
+    };
+    use std::time::Duration;
+
+    const PREVIOUS_PAGE: &str = "menu_previous_page";
+    const NEXT_PAGE: &str = "menu_next_page";
+    const PAGE_NUMBER: &str = "menu_page_number";
+
+    /// Options to customize a menu.
+    #[derive(Debug, Clone)]
+    pub struct MenuOptions {
+        /// The page the menu starts on. Zero-based.
+        pub page: usize,
+        /// How many seconds the menu waits for a button press before the
+        /// buttons are removed.
+        pub timeout: f32,
+    }
+
+    impl Default for MenuOptions {
+        fn default() -> Self {
+            Self {
+                page: 0,
+                timeout: 60.0,
+            }
+        }
+    }
+
+    /// Sends a menu in the channel of `msg`, which `user` can flip through.
+    ///
+    /// Only presses from `user` are considered. Once nobody has pressed a
+    /// button for `timeout` seconds, the buttons are removed and the menu
+    /// message is returned. A menu with a single page is sent without any
+    /// buttons.
+    ///
+    /// ## Errors
+    ///
+    /// Returns [`Error::SerenityError`] if the menu couldn't be sent or edited.
+    ///
+    /// Returns [`Error::Other`] if there are no pages.
+    ///
+    /// [`Error::SerenityError`]: crate::error::Error::SerenityError
+    /// [`Error::Other`]: crate::error::Error::Other
+    pub async fn menu(
+        ctx: &Context,
+        msg: &Message,
+        user: &User,
+        pages: &[CreateEmbed],
+        options: MenuOptions,
+    ) -> Result<Message, Error> {
+        if pages.is_empty() {
+            return Err(Error::from("A menu needs at least one page"));
+        }
+
+        let mut page = options.page.min(pages.len() - 1);
+
+        let mut menu = msg
+            .channel_id
+            .send_message(ctx, |m| {
+                m.set_embed(pages[page].clone());
+
+                if pages.len() > 1 {
+                    m.components(|c| menu_buttons(c, page, pages.len()));
+                }
+
+                m
+            })
+            .await?;
+
+        if pages.len() == 1 {
+            return Ok(menu);
+        }
+
+        while let Some(interaction) = menu
+            .await_component_interaction(ctx)
+            .author_id(user.id)
+            .timeout(Duration::from_secs_f32(options.timeout))
+            .await
+        {
+            page = match interaction.data.custom_id.as_str() {
+                PREVIOUS_PAGE => page.saturating_sub(1),
+                NEXT_PAGE => (page + 1).min(pages.len() - 1),
+                _ => page,
+            };
+
+            interaction
+                .create_interaction_response(ctx, |r| {
+                    r.kind(InteractionResponseType::UpdateMessage)
+                        .interaction_response_data(|d| {
+                            d.set_embed(pages[page].clone())
+                                .components(|c| menu_buttons(c, page, pages.len()))
+                        })
+                })
+                .await?;
+        }
+
+        menu.edit(ctx, |m| m.components(|c| c)).await?;
+
+        Ok(menu)
+    }
+
+    fn menu_buttons(c: &mut CreateComponents, page: usize, pages: usize) -> &mut CreateComponents {
+        c.create_action_row(|row| {
+            row.create_button(|b| {
+                b.custom_id(PREVIOUS_PAGE)
+                    .emoji(ReactionType::from('◀'))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(page == 0)
+            })
+            .create_button(|b| {
+                b.custom_id(PAGE_NUMBER)
+                    .label(format!("{} / {}", page + 1, pages))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(true)
+            })
+            .create_button(|b| {
+                b.custom_id(NEXT_PAGE)
+                    .emoji(ReactionType::from('▶'))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(page + 1 == pages)
+            })
+        })
+    }
+}
+
 pub mod error {
     use serenity::Error as SerenityError;
     use std::{
@@ -1,9 +1,22 @@
 use crate::{
-    database::{models::ReportStatus, Database, MakeReportEffect, ReportUpdateError},
-    error_handling::handle_err_dms,
+    database::{
+        models::{
+            ArchivedAttachmentModel, ModerationAction, ReportModel, ReportStatus,
+            ServerConfiguration,
+        },
+        ArchiveMessageId, Database, MakeReportEffect, ReportUpdateError,
+    },
+    error_handling::{handle_err, handle_err_dms},
+    state::{RateLimitKey, RateLimited, State},
     view,
 };
-use serenity::{client::Context, model::id::*, prelude::Mentionable};
+use serenity::{
+    client::Context,
+    http::error::{Error as HttpError, ErrorResponse},
+    model::{channel::Message, id::*, user::User, Timestamp},
+    prelude::Mentionable,
+};
+use sqlx::types::chrono::Utc;
 use thiserror::Error;
 
 type ReportId = u64;
@@ -20,24 +33,72 @@ pub enum MakeReportError {
     ViewError(#[from] view::UpdateViewError),
     #[error("An error occurred while updating the report: {0}")]
     UpdateError(#[from] ReportUpdateError),
+    #[error("That action can't be taken on this report")]
+    ActionNotAllowed,
+    #[error("That timeout is too long")]
+    InvalidTimeout,
+    #[error("This report has already been accepted or denied")]
+    ReportClosed,
+    #[error("Unable to check if the user is protected: {0}")]
+    ProtectedError(#[from] ProtectedError),
+}
+
+#[derive(Debug, Error)]
+pub enum ProtectedError {
+    #[error("An SQL error occurred: {0}")]
+    SqlError(#[from] sqlx::Error),
+    #[error("A Discord error occurred: {0}")]
+    DiscordError(#[from] serenity::Error),
 }
 
+/// Discord's error code for a user that isn't a member of the server.
+const UNKNOWN_MEMBER: isize = 10007;
+
+/// Files a report, letting the reporter know if it can't be made. Returns the
+/// ID of the report, if a new one was made.
 pub async fn make_report(
     ctx: &Context,
     db: &Database,
     guild_id: GuildId,
     accuser_user_id: UserId,
     reported_user_id: UserId,
-    reported_channel_id: Option<ChannelId>,
-    reported_message_id: Option<MessageId>,
+    reported_message: Option<(ChannelId, MessageId)>,
     report_reason: Option<&str>,
-) -> Result<(), MakeReportError> {
+    category_id: Option<u64>,
+) -> Result<Option<ReportId>, MakeReportError> {
     // before we make a report, lets ensure that the server is configured
-    if !(db.has_server_config(&guild_id).await?) {
-        return Err(MakeReportError::UnconfiguredServer);
+    let config = db
+        .get_server_config(&guild_id)
+        .await?
+        .ok_or(MakeReportError::UnconfiguredServer)?;
+
+    if let Some(ban) = db.load_report_ban(&guild_id, &accuser_user_id).await? {
+        let mut message = match ban.expires {
+            Some(expires) => format!(
+                "You are banned from making reports on this server until <t:{}:f>",
+                expires.timestamp()
+            ),
+            None => "You are banned from making reports on this server".to_owned(),
+        };
+
+        if let Some(reason) = &ban.reason {
+            message.push_str(&format!(" (reason: {})", reason));
+        }
+
+        // don't return an error since we don't want public chat to get the error
+        handle_err_dms(
+            ctx,
+            accuser_user_id,
+            None,
+            &message,
+            "Banned From Reporting",
+        )
+        .await;
+
+        return Ok(None);
     }
 
-    if db.load_protected_user(&guild_id, &reported_user_id).await? {
+    if is_protected(ctx, db, guild_id, reported_user_id).await? {
         // don't return an error since we don't want public chat to get the error
         handle_err_dms(
             &ctx,
@@ -51,19 +112,60 @@ pub async fn make_report(
         )
         .await;
 
-        return Ok(());
+        return Ok(None);
+    }
+
+    if report_reason.map_or(true, |r| r.trim().is_empty())
+        && needs_reason(db, &config, guild_id, accuser_user_id).await?
+    {
+        // don't return an error since we don't want public chat to get the error
+        handle_err_dms(
+            ctx,
+            accuser_user_id,
+            None,
+            "Many of your previous reports on this server were denied, so your reports need a reason. Use the `/report` command to report a user with a reason.",
+            "Reason Required",
+        )
+        .await;
+
+        return Ok(None);
+    }
+
+    // this is checked last, so that reports which would've been rejected
+    // anyway don't use up the reporter's allowance
+    if let Err(limited) = take_report_token(ctx, &config, guild_id, accuser_user_id).await {
+        let message = match limited.key {
+            RateLimitKey::User(..) => "You're making reports too quickly",
+            RateLimitKey::Guild(..) => "This server is receiving a lot of reports right now",
+        };
+        let retry_at = Utc::now().timestamp() + limited.retry_after.as_secs() as i64 + 1;
+
+        // don't return an error since we don't want public chat to get the error
+        handle_err_dms(
+            ctx,
+            accuser_user_id,
+            None,
+            &format!("{} - you can report again <t:{}:R>", message, retry_at),
+            "Slow Down",
+        )
+        .await;
+
+        return Ok(None);
     }
 
     let user_reporting = accuser_user_id.to_user(&ctx).await?;
     let reported_user = reported_user_id.to_user(&ctx).await?;
 
-    let mut reported_message = None;
+    let reported_message_id = reported_message.map(|(_, m)| m);
+    let reported_message = match reported_message {
+        Some((c, m)) => Some(c.message(&ctx, m).await?),
+        None => None,
+    };
 
-    if let Some(c) = reported_channel_id {
-        if let Some(m) = reported_message_id {
-            reported_message = Some(c.message(&ctx, m).await?);
-        }
-    }
+    let reported_attachments = match &reported_message {
+        Some(message) => archive_attachments(db, guild_id, message).await?,
+        None => Vec::new(),
+    };
 
     let effect = db
         .make_report(
@@ -71,6 +173,7 @@ pub async fn make_report(
             &user_reporting,
             &reported_user,
             reported_message.as_ref(),
+            &reported_attachments,
             report_reason,
         )
         .await?;
@@ -89,11 +192,284 @@ pub async fn make_report(
         )
         .await;
 
-        return Ok(());
+        return Ok(None);
+    };
+
+    if let MakeReportEffect::Created(report_id, _) = effect {
+        // categories can be deleted while a report is being made
+        if let Some(category) = category_id.and_then(|id| config.category(id)) {
+            db.set_report_category(report_id, category.id).await?;
+        }
+    }
+
+    if let (MakeReportEffect::Created(report_id, archive_id), Some(message)) =
+        (&effect, &reported_message)
+    {
+        if !flag_message(ctx, db, &config, guild_id, message, *report_id, *archive_id).await? {
+            // don't return an error since we don't want public chat to get the error
+            handle_err_dms(
+                ctx,
+                accuser_user_id,
+                None,
+                &format!(
+                    "Your report (ID: {}) has been recorded, and will be sent to the moderators once {} members have flagged this message",
+                    report_id, config.report_threshold
+                ),
+                "Report Pending",
+            )
+            .await;
+
+            return Ok(Some(*report_id));
+        }
+    }
+
+    if let MakeReportEffect::Created(report_id, _) = effect {
+        group_report(
+            db,
+            &config,
+            guild_id,
+            reported_user_id,
+            reported_message_id,
+            report_id,
+        )
+        .await?;
+    }
+
+    let report_id = match effect {
+        MakeReportEffect::Created(report_id, _) => Some(report_id),
+        _ => None,
     };
 
     view::update_report_view(ctx, &db, effect).await?;
 
+    Ok(report_id)
+}
+
+/// Counts a new report towards the server's thresholds for the reported
+/// message, deleting the message once enough members have flagged it. Returns
+/// whether the report can be sent to the moderators, and sends any reports
+/// that were held back once the message reaches the report threshold.
+async fn flag_message(
+    ctx: &Context,
+    db: &Database,
+    config: &ServerConfiguration,
+    guild_id: GuildId,
+    message: &Message,
+    report_id: ReportId,
+    archive_id: Option<ArchiveMessageId>,
+) -> Result<bool, MakeReportError> {
+    let flags = db.load_message_flag_count(&guild_id, &message.id).await?;
+
+    // the threshold can be passed without being reached exactly, such as when
+    // it's lowered, so the message is marked to only be deleted once
+    let reached_auto_hide = config
+        .auto_hide_threshold
+        .map_or(false, |t| flags >= t as u64);
+    if reached_auto_hide && db.mark_message_hidden(guild_id, message.id).await? {
+        if let Err(error) = message.delete(ctx).await {
+            log::warn!(
+                "couldn't hide message {} after {} flags: {}",
+                message.id,
+                flags,
+                error
+            );
+        }
+    }
+
+    if flags < config.report_threshold as u64 {
+        db.hold_pending_report(report_id, guild_id, message.id, archive_id)
+            .await?;
+        return Ok(false);
+    }
+
+    for (pending_id, preview_archive_id) in db.release_pending_reports(guild_id, message.id).await?
+    {
+        group_report(
+            db,
+            config,
+            guild_id,
+            message.author.id,
+            Some(message.id),
+            pending_id,
+        )
+        .await?;
+
+        let effect = MakeReportEffect::Created(pending_id, preview_archive_id);
+        view::update_report_view(ctx, db, effect).await?;
+    }
+
+    Ok(true)
+}
+
+/// Groups a report that's being sent to the moderators into a case, or else a
+/// burst, with the other reports against the reported user. Reports that are
+/// held back aren't grouped until they're sent.
+async fn group_report(
+    db: &Database,
+    config: &ServerConfiguration,
+    guild_id: GuildId,
+    reported_user_id: UserId,
+    reported_message_id: Option<MessageId>,
+    report_id: ReportId,
+) -> Result<(), sqlx::Error> {
+    let case = db
+        .add_report_to_case(
+            guild_id,
+            reported_user_id,
+            reported_message_id,
+            report_id,
+            config.case_window,
+        )
+        .await?;
+
+    // reports in a case are already shown together on one mod view
+    if case.is_none() {
+        db.add_report_to_burst(
+            guild_id,
+            reported_user_id,
+            report_id,
+            config.burst_threshold,
+            config.burst_window,
+        )
+        .await?;
+    }
+
+    Ok(())
+}
+
+async fn take_report_token(
+    ctx: &Context,
+    config: &ServerConfiguration,
+    guild_id: GuildId,
+    accuser_user_id: UserId,
+) -> Result<(), RateLimited> {
+    let data = ctx.data.read().await;
+    let state = data.get::<State>().unwrap();
+    state
+        .take_report_token(config, guild_id, accuser_user_id)
+        .await
+}
+
+/// Checks if a user can't be reported, either because they're protected or
+/// because they have a protected role.
+pub async fn is_protected(
+    ctx: &Context,
+    db: &Database,
+    guild_id: GuildId,
+    user_id: UserId,
+) -> Result<bool, ProtectedError> {
+    if db.load_protected_user(&guild_id, &user_id).await? {
+        return Ok(true);
+    }
+
+    let protected_roles = db.load_protected_roles(&guild_id).await?;
+
+    if protected_roles.is_empty() {
+        return Ok(false);
+    }
+
+    let member = match guild_id.member(ctx, user_id).await {
+        Ok(member) => member,
+        // users that aren't in the server don't have any roles
+        Err(serenity::Error::Http(error)) if is_unknown_member(&error) => return Ok(false),
+        Err(error) => return Err(error.into()),
+    };
+
+    Ok(member.roles.iter().any(|r| protected_roles.contains(r)))
+}
+
+fn is_unknown_member(error: &HttpError) -> bool {
+    matches!(
+        error,
+        HttpError::UnsuccessfulRequest(ErrorResponse { error, .. }) if error.code == UNKNOWN_MEMBER
+    )
+}
+
+/// Checks if the server requires a reason from the reporter, because their
+/// reputation is below the server's threshold.
+async fn needs_reason(
+    db: &Database,
+    config: &ServerConfiguration,
+    guild_id: GuildId,
+    accuser_user_id: UserId,
+) -> Result<bool, sqlx::Error> {
+    if !config.reputation_requires_reason {
+        return Ok(false);
+    }
+
+    let reputation = db
+        .load_reporter_reputation(&guild_id, &accuser_user_id)
+        .await?;
+
+    Ok(config.is_below_reputation_threshold(&reputation))
+}
+
+/// Prepares the attachments of a message for archival. Every attachment has its
+/// URL archived, but the raw bytes are only downloaded if the server has opted
+/// into it and the attachment fits within the server's limits.
+pub async fn archive_attachments(
+    db: &Database,
+    guild_id: GuildId,
+    message: &Message,
+) -> Result<Vec<ArchivedAttachmentModel>, sqlx::Error> {
+    let config = db.get_server_config(&guild_id).await?;
+
+    let mut used = match config.as_ref().and_then(|c| c.attachment_allowance(0)) {
+        Some(_) => db.load_archived_attachment_usage(&guild_id).await?,
+        // don't bother computing usage if we'll never download anything
+        None => 0,
+    };
+
+    let mut attachments = Vec::with_capacity(message.attachments.len());
+
+    for (idx, attachment) in message.attachments.iter().enumerate() {
+        let allowance = config.as_ref().and_then(|c| c.attachment_allowance(used));
+
+        let bytes = match allowance {
+            Some(allowance) if attachment.size <= allowance => match attachment.download().await {
+                Ok(bytes) => {
+                    used += bytes.len() as u64;
+                    Some(bytes)
+                }
+                Err(error) => {
+                    log::warn!(
+                        "couldn't download attachment '{}': {}",
+                        attachment.url,
+                        error
+                    );
+                    None
+                }
+            },
+            _ => None,
+        };
+
+        attachments.push(ArchivedAttachmentModel {
+            idx: idx as u64,
+            url: attachment.url.clone(),
+            attachment: bytes,
+        });
+    }
+
+    Ok(attachments)
+}
+
+/// Attaches evidence to a report, archiving each message (and its attachments)
+/// like a reported message. Linked messages are in the server, while the rest
+/// were sent to the bot by the reporter.
+pub async fn add_report_evidence(
+    ctx: &Context,
+    db: &Database,
+    guild_id: GuildId,
+    report_id: ReportId,
+    evidence: &[(Message, bool)],
+) -> Result<(), MakeReportError> {
+    for (message, is_linked) in evidence {
+        let attachments = archive_attachments(db, guild_id, message).await?;
+        db.add_report_evidence(report_id, message, &attachments, *is_linked)
+            .await?;
+    }
+
+    view::update_report_view(ctx, db, MakeReportEffect::Updated(report_id)).await?;
     Ok(())
 }
 
@@ -101,9 +477,11 @@ pub async fn update_report_reason(
     ctx: &Context,
     db: &Database,
     report_id: ReportId,
+    actor: UserId,
     reason: String,
 ) -> Result<(), MakeReportError> {
-    db.update_report(report_id, Some(reason), None).await?;
+    db.update_report(report_id, actor, Some(reason), None)
+        .await?;
     view::update_report_view(&ctx, &db, MakeReportEffect::Updated(report_id)).await?;
     Ok(())
 }
@@ -112,10 +490,432 @@ pub async fn update_report_status(
     ctx: &Context,
     db: &Database,
     report_id: ReportId,
+    actor: UserId,
     status: ReportStatus,
 ) -> Result<(), MakeReportError> {
-    db.update_report(report_id, Option::<String>::None, Some(status))
+    db.update_report(report_id, actor, Option::<String>::None, Some(status))
         .await?;
     view::update_report_view(&ctx, &db, MakeReportEffect::Updated(report_id)).await?;
     Ok(())
 }
+
+/// The most characters a moderator note may have, so that a few notes fit in
+/// the mod view.
+pub const NOTE_MAX_LENGTH: usize = 500;
+
+/// Attaches a private note to a report, which is shown on the mod view.
+pub async fn add_report_note(
+    ctx: &Context,
+    db: &Database,
+    report_id: ReportId,
+    author: UserId,
+    content: &str,
+) -> Result<(), MakeReportError> {
+    db.add_report_note(report_id, author, content).await?;
+    view::update_report_view(ctx, db, MakeReportEffect::Updated(report_id)).await?;
+    Ok(())
+}
+
+/// The most characters a message relayed between the moderators and a
+/// reporter may have, so it fits in an embed.
+pub const RELAY_MAX_LENGTH: usize = 2000;
+
+/// The most characters a report's reason may have. Embed fields are limited to
+/// 1024 characters, so this leaves some breathing room.
+pub const REASON_MAX_LENGTH: usize = 1000;
+
+/// Sends a moderator's message to the reporter, as a reply to their view of
+/// the report. Unless the server shows moderators by name, the reporter only
+/// sees that it came from the server's moderators.
+pub async fn relay_to_reporter(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: &User,
+    content: &str,
+) -> Result<(), MakeReportError> {
+    let config = db
+        .get_server_config(&report.guild_id)
+        .await?
+        .ok_or(MakeReportError::UnconfiguredServer)?;
+
+    let view = db.load_user_view(report.id).await?;
+    let dms = report.accuser_user_id.create_dm_channel(ctx).await?;
+    let server = (report.guild_id.name(ctx)).unwrap_or_else(|| "the server".to_owned());
+
+    let msg = dms
+        .send_message(ctx, |m| {
+            if let Some(view) = &view {
+                m.reference_message((dms.id, view.message_id));
+            }
+
+            m.embed(|e| {
+                match config.relay_anonymous {
+                    true => e.author(|a| a.name(format!("Moderators of {}", server))),
+                    false => e.author(|a| {
+                        a.name(format!("{} ({})", moderator.tag(), server))
+                            .icon_url(moderator.face())
+                    }),
+                };
+
+                e.title(format!("Message about Report #{}", report.id))
+                    .description(content)
+                    .footer(|f| f.text("Reply to this message to answer the moderators"))
+            })
+        })
+        .await?;
+
+    db.save_relay_message(report.id, moderator.id, true, content, dms.id, msg.id)
+        .await?;
+
+    Ok(())
+}
+
+/// Sends the reporter's answer to the moderators, in the thread of the mod
+/// view showing the report if it has one.
+pub async fn relay_to_moderators(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    reporter: &User,
+    content: &str,
+) -> Result<(), MakeReportError> {
+    let config = db
+        .get_server_config(&report.guild_id)
+        .await?
+        .ok_or(MakeReportError::UnconfiguredServer)?;
+
+    // reports grouped into a case are shown on the lead report's mod view
+    let lead_id = match db.load_report_case(report.id).await? {
+        Some(case) => case.lead_report_id,
+        None => report.id,
+    };
+
+    let view = db.load_mod_view(lead_id).await?;
+    let channel_id = match &view {
+        Some(view) => view.thread_id.unwrap_or(view.channel_id),
+        None => config.reports_channel_for(report.category_id),
+    };
+
+    let msg = channel_id
+        .send_message(ctx, |m| {
+            if let Some(view) = view.as_ref().filter(|v| v.channel_id == channel_id) {
+                m.reference_message((view.channel_id, view.message_id));
+            }
+
+            m.embed(|e| {
+                e.author(|a| a.name(reporter.tag()).icon_url(reporter.face()))
+                    .title(format!("Reply about Report #{}", report.id))
+                    .description(content)
+                    .footer(|f| f.text("Reply to this message to answer the reporter"))
+            })
+        })
+        .await?;
+
+    db.save_relay_message(report.id, reporter.id, false, content, channel_id, msg.id)
+        .await?;
+
+    Ok(())
+}
+
+/// Records a moderator as the handler of a report, putting it under review.
+/// Unclaiming releases the report back to being unhandled. Reports which have
+/// already been accepted or denied are left alone.
+pub async fn claim_report(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+    is_claimed: bool,
+) -> Result<(), MakeReportError> {
+    match report.status {
+        ReportStatus::Unhandled | ReportStatus::Reviewing => {}
+        _ => return Ok(()),
+    };
+
+    let (handler, new_status) = match is_claimed {
+        true => (Some(moderator), ReportStatus::Reviewing),
+        false => (None, ReportStatus::Unhandled),
+    };
+
+    db.update_mod_view_handler(report.id, moderator, handler)
+        .await?;
+    update_report_status(ctx, db, report.id, moderator, new_status).await
+}
+
+/// Accepts or denies a report on behalf of a moderator, letting the reporter
+/// know about the outcome. If the report leads a case, every open report in
+/// the case is resolved along with it. Reports that are already resolved stay
+/// as they are.
+pub async fn finalize_report(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+    is_accepted: bool,
+) -> Result<(), MakeReportError> {
+    if !report.status.is_open() {
+        return Err(MakeReportError::ReportClosed);
+    }
+
+    // the reported user is warned once, however many reports are resolved
+    if is_accepted {
+        warn_reported_user(ctx, db, report, moderator).await?;
+    }
+
+    resolve_report(ctx, db, report, moderator, is_accepted).await
+}
+
+async fn resolve_report(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+    is_accepted: bool,
+) -> Result<(), MakeReportError> {
+    finalize_single_report(ctx, db, report, moderator, is_accepted).await?;
+
+    let case = match db.load_report_case(report.id).await? {
+        Some(case) if case.lead_report_id == report.id => case,
+        _ => return Ok(()),
+    };
+
+    let reports = db.load_case_reports(case.id).await?;
+
+    for grouped in reports
+        .iter()
+        .filter(|r| r.id != report.id && r.status.is_open())
+    {
+        finalize_single_report(ctx, db, grouped, moderator, is_accepted).await?;
+    }
+
+    Ok(())
+}
+
+async fn finalize_single_report(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+    is_accepted: bool,
+) -> Result<(), MakeReportError> {
+    let new_status = match is_accepted {
+        true => ReportStatus::Accepted,
+        false => ReportStatus::Denied,
+    };
+
+    // reports shown on a case's or a burst's view have no mod view to update
+    if db.load_mod_view(report.id).await?.is_some() {
+        db.update_mod_view_handler(report.id, moderator, Some(moderator))
+            .await?;
+    }
+    update_report_status(ctx, db, report.id, moderator, new_status).await?;
+
+    if report.status != new_status {
+        if let Some(user_model) = db.load_user_view(report.id).await? {
+            let dms = report.accuser_user_id.create_dm_channel(ctx).await?;
+
+            dms.send_message(ctx, |m| {
+                m.content(format!(
+                    "Your report (#{}) has been {}!",
+                    report.id,
+                    new_status.into_human_status()
+                ))
+                .reference_message((dms.id, user_model.message_id))
+            })
+            .await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Accepts a report after taking a moderation action against the reported
+/// user. The action is taken first, so a failed action leaves the report as
+/// it was.
+pub async fn accept_report_with_action(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+    action: ModerationAction,
+) -> Result<(), MakeReportError> {
+    let config = db
+        .get_server_config(&report.guild_id)
+        .await?
+        .ok_or(MakeReportError::UnconfiguredServer)?;
+
+    if !config.allows_action(action.kind()) {
+        return Err(MakeReportError::ActionNotAllowed);
+    }
+
+    if !report.status.is_open() {
+        return Err(MakeReportError::ReportClosed);
+    }
+
+    let reason = format!("Report #{} accepted", report.id);
+
+    match action {
+        ModerationAction::DeleteMessage => {
+            let (channel_id, message_id) = (report.channel_id)
+                .zip(report.message_id)
+                .ok_or(MakeReportError::ActionNotAllowed)?;
+
+            channel_id.delete_message(ctx, message_id).await?;
+        }
+        ModerationAction::Timeout(duration) => {
+            let until =
+                Timestamp::from_unix_timestamp(Utc::now().timestamp() + duration.as_secs() as i64)
+                    .map_err(|_| MakeReportError::InvalidTimeout)?;
+
+            (report.guild_id)
+                .edit_member(ctx, report.reported_user_id, |m| {
+                    m.disable_communication_until_datetime(until)
+                })
+                .await?;
+        }
+        ModerationAction::Kick => {
+            (report.guild_id)
+                .kick_with_reason(ctx, report.reported_user_id, &reason)
+                .await?
+        }
+        ModerationAction::Ban => {
+            (report.guild_id)
+                .ban_with_reason(ctx, report.reported_user_id, 0, &reason)
+                .await?
+        }
+    };
+
+    db.add_report_action(report.id, moderator, action, &view::display_action(action))
+        .await?;
+    finalize_report(ctx, db, report, moderator, true).await
+}
+
+/// Accepts or denies every open report in a burst on behalf of a moderator.
+pub async fn finalize_burst(
+    ctx: &Context,
+    db: &Database,
+    burst_id: u64,
+    moderator: UserId,
+    is_accepted: bool,
+) -> Result<(), MakeReportError> {
+    let reports = db.load_burst_reports(burst_id).await?;
+    let open = reports.iter().filter(|r| r.status.is_open());
+
+    // every report in a burst is against the same user, who's only warned once
+    if let (true, Some(report)) = (is_accepted, open.clone().next()) {
+        warn_reported_user(ctx, db, report, moderator).await?;
+    }
+
+    for report in open {
+        resolve_report(ctx, db, report, moderator, is_accepted).await?;
+    }
+
+    Ok(())
+}
+
+/// The warning sent to reported users when a server hasn't set its own.
+pub const DEFAULT_WARNING_TEMPLATE: &str = "A moderator of **{server}** has reviewed a report against you and found that you broke a rule.\n\n**Rule:** {rule}\n**Note from the moderators:** {note}";
+
+/// The most characters a warning template may have, leaving room for the
+/// placeholders to be filled in.
+pub const WARNING_TEMPLATE_MAX_LENGTH: usize = 1000;
+
+/// Fills in the placeholders of a warning template. Nothing about the
+/// reporter is available to the template.
+pub fn render_warning(
+    template: &str,
+    server: &str,
+    rule: Option<&str>,
+    note: Option<&str>,
+) -> String {
+    let placeholders = [
+        ("\\n", "\n"),
+        ("{server}", server),
+        ("{rule}", rule.unwrap_or("*Not specified*")),
+        ("{note}", note.unwrap_or("*None*")),
+    ];
+
+    // placeholders are filled in a single pass, so placeholders in the values
+    // themselves (like a note mentioning `{rule}`) are left as they are
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find(|c: char| c == '{' || c == '\\') {
+        rendered.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        let (placeholder, value) = (placeholders.iter())
+            .find(|(placeholder, _)| rest.starts_with(placeholder))
+            .copied()
+            .unwrap_or((&rest[..1], &rest[..1]));
+
+        rendered.push_str(value);
+        rest = &rest[placeholder.len()..];
+    }
+
+    rendered.push_str(rest);
+    rendered
+}
+
+/// Lets the reported user know that a report against them was accepted, if
+/// the server warns reported users. Warnings that can't be delivered are
+/// reported in the reports channel, rather than stopping the report from
+/// being accepted.
+async fn warn_reported_user(
+    ctx: &Context,
+    db: &Database,
+    report: &ReportModel,
+    moderator: UserId,
+) -> Result<(), MakeReportError> {
+    let config = db
+        .get_server_config(&report.guild_id)
+        .await?
+        .ok_or(MakeReportError::UnconfiguredServer)?;
+
+    if !config.warn_reported_users {
+        return Ok(());
+    }
+
+    let warning = db.load_report_warning(report.id).await?;
+    if warning.as_ref().and_then(|w| w.sent).is_some() {
+        return Ok(());
+    }
+
+    let server = (report.guild_id.name(ctx)).unwrap_or_else(|| "the server".to_owned());
+    let content = render_warning(
+        (config.warning_template.as_deref()).unwrap_or(DEFAULT_WARNING_TEMPLATE),
+        &server,
+        warning.as_ref().and_then(|w| w.rule.as_deref()),
+        warning.as_ref().and_then(|w| w.note.as_deref()),
+    );
+
+    let sent = async {
+        let dms = report.reported_user_id.create_dm_channel(ctx).await?;
+        dms.send_message(ctx, |m| {
+            m.embed(|e| e.title("Warning").description(&content))
+        })
+        .await
+    }
+    .await;
+
+    match sent {
+        Ok(_) => db.mark_warning_sent(report.id, moderator, &content).await?,
+        Err(error) => {
+            handle_err(
+                ctx,
+                config.reports_channel_for(report.category_id),
+                None,
+                &error,
+                format!(
+                    "Couldn't warn {} about report #{}",
+                    report.reported_user_id.mention(),
+                    report.id
+                ),
+            )
+            .await
+        }
+    };
+
+    Ok(())
+}
@@ -1,11 +1,17 @@
 use serenity::{
     model::{
         channel::Message,
-        id::{ChannelId, MessageId, UserId},
+        id::{ChannelId, GuildId, MessageId, UserId},
     },
     prelude::*,
 };
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::atomic::AtomicBool,
+    time::{Duration, Instant},
+};
+
+use crate::database::models::{RateLimit, ServerConfiguration};
 
 /// State is used to store small bits of information about users, to make the
 /// bot feel nicer to use.
@@ -16,6 +22,10 @@ pub struct State {
     // TODO: use async concurrent hashmap
     pub users: RwLock<HashMap<UserId, UserState>>,
     pub pinned_msgs: RwLock<Vec<(ChannelId, MessageId)>>,
+    pub report_buckets: RwLock<HashMap<RateLimitKey, TokenBucket>>,
+    /// Whether the task reminding moderators about overdue reports has been
+    /// started, since `ready` can fire more than once.
+    pub escalator_started: AtomicBool,
 }
 
 impl TypeMapKey for State {
@@ -27,6 +37,8 @@ impl State {
         Self {
             users: RwLock::new(HashMap::new()),
             pinned_msgs: RwLock::new(Vec::new()),
+            report_buckets: RwLock::new(HashMap::new()),
+            escalator_started: AtomicBool::new(false),
         }
     }
 
@@ -36,6 +48,53 @@ impl State {
         msg.pin(ctx).await
     }
 
+    /// Takes a token from the reporter's and the server's report buckets, so
+    /// that a report can be made. Nothing is taken unless every bucket has a
+    /// token to spare.
+    pub async fn take_report_token(
+        &self,
+        config: &ServerConfiguration,
+        guild_id: GuildId,
+        user_id: UserId,
+    ) -> Result<(), RateLimited> {
+        let now = Instant::now();
+        let limits = [
+            (
+                RateLimitKey::User(guild_id, user_id),
+                config.user_rate_limit,
+            ),
+            (RateLimitKey::Guild(guild_id), config.guild_rate_limit),
+        ];
+
+        let mut buckets = self.report_buckets.write().await;
+
+        for (key, limit) in limits.iter().filter(|(_, l)| l.is_enabled()) {
+            let bucket = (buckets.entry(*key)).or_insert_with(|| TokenBucket::new(*limit, now));
+            bucket.refill(*limit, now);
+
+            if let Some(retry_after) = bucket.wait_time() {
+                return Err(RateLimited {
+                    key: *key,
+                    retry_after,
+                });
+            }
+        }
+
+        for (key, _) in limits.iter().filter(|(_, l)| l.is_enabled()) {
+            if let Some(bucket) = buckets.get_mut(key) {
+                bucket.take();
+            }
+        }
+
+        // a full bucket is the same as a missing one, so forget about them
+        // once there's a lot to keep track of
+        if buckets.len() > MAX_REPORT_BUCKETS {
+            buckets.retain(|_, bucket| !bucket.is_full(now));
+        }
+
+        Ok(())
+    }
+
     pub async fn get_user(&self, id: &UserId) -> UserState {
         let lock = self.users.read().await;
         match lock.get(&id) {
@@ -54,6 +113,76 @@ impl State {
     }
 }
 
+/// How many report buckets are kept before the full ones are cleaned up.
+const MAX_REPORT_BUCKETS: usize = 1024;
+
+/// What a report bucket limits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RateLimitKey {
+    /// A user making reports in a server.
+    User(GuildId, UserId),
+    /// Every report made in a server.
+    Guild(GuildId),
+}
+
+/// A report couldn't be made because a bucket ran out of tokens.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimited {
+    pub key: RateLimitKey,
+    /// How long until the bucket has a token again.
+    pub retry_after: Duration,
+}
+
+/// A token bucket, which holds up to `limit.count` tokens and gains them back
+/// at a steady rate over `limit.per`.
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    limit: RateLimit,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit, now: Instant) -> Self {
+        Self {
+            limit,
+            tokens: limit.count as f64,
+            updated: now,
+        }
+    }
+
+    /// Tokens gained back per second.
+    fn rate(&self) -> f64 {
+        self.limit.count as f64 / self.limit.per.as_secs_f64()
+    }
+
+    fn refill(&mut self, limit: RateLimit, now: Instant) {
+        // the server may have changed its limit since the last report
+        self.limit = limit;
+
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate()).min(limit.count as f64);
+        self.updated = now;
+    }
+
+    fn take(&mut self) {
+        self.tokens -= 1.0;
+    }
+
+    /// How long until there's a token to take, if there isn't one already.
+    fn wait_time(&self) -> Option<Duration> {
+        match self.tokens >= 1.0 {
+            true => None,
+            false => Some(Duration::from_secs_f64((1.0 - self.tokens) / self.rate())),
+        }
+    }
+
+    fn is_full(&self, now: Instant) -> bool {
+        let elapsed = now.duration_since(self.updated).as_secs_f64();
+        self.tokens + elapsed * self.rate() >= self.limit.count as f64
+    }
+}
+
 #[derive(Clone)]
 pub struct UserState {
     pub in_setup: bool,
@@ -1,2 +1,5 @@
+mod update_burst_view;
+pub use update_burst_view::*;
+
 mod update_report_view;
 pub use update_report_view::*;
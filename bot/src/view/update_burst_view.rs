@@ -0,0 +1,262 @@
+use serenity::{
+    builder::{CreateComponents, CreateEmbed, CreateMessage},
+    client::Context,
+    model::{application::component::ButtonStyle, channel::ReactionType},
+    prelude::Mentionable,
+};
+
+use super::{
+    create_forum_post, display_evidence, is_forum, load_evidence, truncate, UpdateViewError,
+    THREAD_NAME_MAX_LENGTH,
+};
+use crate::{
+    database::{models::*, Database},
+    state::State,
+};
+
+/// The most characters the list of reports in a burst may take up, leaving
+/// room for the rest of the embed description.
+const REPORT_LIST_MAX_LENGTH: usize = 3800;
+
+/// Shows every report in a burst in a single message in the reports channel.
+/// Burst views always use buttons, since there are no reaction controls for
+/// them.
+pub async fn update_burst_view(
+    ctx: &Context,
+    db: &Database,
+    burst: &ReportBurstModel,
+) -> Result<(), UpdateViewError> {
+    let maybe_config = db.get_server_config(&burst.guild_id).await?;
+    let config = maybe_config.ok_or(UpdateViewError::UnconfiguredServer)?;
+
+    let reports = db.load_burst_reports(burst.id).await?;
+    let evidence = load_evidence(db, &reports).await?;
+    let has_open = reports.iter().any(|r| r.status.is_open());
+
+    // the burst is shown where its first report would've been
+    let view = burst.channel_id.zip(burst.message_id);
+    let mut channel_id = match view {
+        Some((channel_id, _)) => channel_id,
+        None => config.reports_channel_for(reports.first().and_then(|r| r.category_id)),
+    };
+    let mut thread_id = burst.thread_id;
+
+    let reported = burst.reported_user_id.to_user(ctx).await?;
+    let thread_name = truncate(
+        &format!("Burst #{} {}", burst.id, reported.name),
+        THREAD_NAME_MAX_LENGTH,
+    );
+
+    let msg = match view {
+        Some((_, message_id)) => {
+            // messages in archived threads can't be edited
+            if thread_id == Some(channel_id) {
+                channel_id.edit_thread(ctx, |t| t.archived(false)).await?;
+            }
+
+            channel_id
+                .edit_message(ctx, message_id, |m| {
+                    m.components(|c| display_burst_view_buttons(c, burst, has_open))
+                        .embed(|e| display_burst_view(ctx, e, burst, &reports, &evidence))
+                })
+                .await?
+        }
+        None => {
+            let mut create = CreateMessage::default();
+            create
+                .components(|c| display_burst_view_buttons(c, burst, has_open))
+                .embed(|e| display_burst_view(ctx, e, burst, &reports, &evidence));
+
+            match is_forum(ctx, channel_id).await? {
+                true => {
+                    let (post_id, msg) =
+                        create_forum_post(ctx, channel_id, &thread_name, create).await?;
+
+                    // the burst view is the starter message of the post
+                    channel_id = post_id;
+                    thread_id = Some(post_id);
+                    msg
+                }
+                false => {
+                    channel_id
+                        .send_message(ctx, |m| {
+                            *m = create;
+                            m
+                        })
+                        .await?
+                }
+            }
+        }
+    };
+
+    if config.mod_view_threads && thread_id.is_none() && has_open {
+        let thread = channel_id
+            .create_public_thread(ctx, msg.id, |t| t.name(&thread_name))
+            .await?;
+        thread_id = Some(thread.id);
+    }
+
+    match has_open {
+        true => {
+            let read = ctx.data.read().await;
+            let state = read.get::<State>().unwrap();
+            state.pin_msg(&msg, ctx).await?;
+        }
+        false => {
+            msg.unpin(ctx).await?;
+        }
+    }
+
+    if view.is_none() || thread_id != burst.thread_id {
+        db.save_burst_view(burst.id, channel_id, msg.id, thread_id)
+            .await?;
+    }
+
+    // the discussion is over once every report is resolved
+    if let Some(thread_id) = thread_id.filter(|_| !has_open) {
+        thread_id.edit_thread(ctx, |t| t.archived(true)).await?;
+    }
+
+    // reports that were shown on their own before the burst started are only
+    // shown on the burst view from now on
+    for report in &reports {
+        if let Some(mod_view) = db.load_mod_view(report.id).await? {
+            retire_mod_view(ctx, &mod_view).await;
+            db.delete_mod_view(report.id).await?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Removes the mod view of a report, along with the forum post it's in. Mod
+/// views that were already removed by hand are skipped.
+async fn retire_mod_view(ctx: &Context, mod_view: &ModViewModel) {
+    let result = match mod_view.is_forum_post() {
+        true => mod_view.channel_id.delete(ctx).await.map(|_| ()),
+        false => {
+            (mod_view.channel_id)
+                .delete_message(ctx, mod_view.message_id)
+                .await
+        }
+    };
+
+    if let Err(error) = result {
+        log::warn!(
+            "couldn't remove the mod view of report {}: {}",
+            mod_view.report_id,
+            error
+        );
+    }
+
+    // the thread stays around for its history, but the discussion moves to
+    // the burst
+    if let Some(thread_id) = mod_view.thread_id.filter(|_| !mod_view.is_forum_post()) {
+        if let Err(error) = thread_id.edit_thread(ctx, |t| t.archived(true)).await {
+            log::warn!(
+                "couldn't archive the thread of report {}: {}",
+                mod_view.report_id,
+                error
+            );
+        }
+    }
+}
+
+pub const BURST_REFRESH_BUTTON: &str = "burst_view_refresh";
+pub const BURST_ACCEPT_BUTTON: &str = "burst_view_accept";
+pub const BURST_DENY_BUTTON: &str = "burst_view_deny";
+
+fn display_burst_view_buttons<'a>(
+    c: &'a mut CreateComponents,
+    burst: &ReportBurstModel,
+    has_open: bool,
+) -> &'a mut CreateComponents {
+    c.create_action_row(|row| {
+        row.create_button(|b| {
+            b.custom_id(format!("{}:{}", BURST_REFRESH_BUTTON, burst.id))
+                .label("Refresh")
+                .emoji(ReactionType::Unicode("🔄".to_owned()))
+                .style(ButtonStyle::Secondary)
+        })
+        .create_button(|b| {
+            b.custom_id(format!("{}:{}", BURST_DENY_BUTTON, burst.id))
+                .label("Deny All Open")
+                .emoji(ReactionType::Unicode("❌".to_owned()))
+                .style(ButtonStyle::Danger)
+                .disabled(!has_open)
+        })
+        .create_button(|b| {
+            b.custom_id(format!("{}:{}", BURST_ACCEPT_BUTTON, burst.id))
+                .label("Accept All Open")
+                .emoji(ReactionType::Unicode("✅".to_owned()))
+                .style(ButtonStyle::Success)
+                .disabled(!has_open)
+        })
+    })
+}
+
+fn display_burst_view<'a>(
+    ctx: &Context,
+    e: &'a mut CreateEmbed,
+    burst: &ReportBurstModel,
+    reports: &[ReportModel],
+    evidence: &[ReportEvidenceModel],
+) -> &'a mut CreateEmbed {
+    let open = reports.iter().filter(|r| r.status.is_open()).count();
+
+    let mut description = format!(
+        "{} is being reported in quick succession, so reports against them since <t:{}:f> are collapsed into this message. **{}** of **{}** are still open.\n\n",
+        burst.reported_user_id.mention(),
+        burst.created_at.timestamp(),
+        open,
+        reports.len()
+    );
+
+    let mut list = String::new();
+    for (idx, report) in reports.iter().enumerate() {
+        let mut line = format!(
+            "`#{}` {} by {}",
+            report.id,
+            report.status.into_human_status(),
+            report.accuser_user_id.mention()
+        );
+
+        if let Some(url) = report.url() {
+            line.push_str(&format!(" ([message]({}))", url));
+        }
+
+        if let Some(reason) = &report.reason {
+            line.push_str(&format!(": {}", truncate(reason, 80)));
+        }
+
+        if list.len() + line.len() > REPORT_LIST_MAX_LENGTH {
+            list.push_str(&format!("*...and {} more*", reports.len() - idx));
+            break;
+        }
+
+        list.push_str(&line);
+        list.push('\n');
+    }
+
+    description.push_str(&list);
+
+    e.title(format!("Report Burst (#{})", burst.id))
+        .description(description)
+        .timestamp(burst.updated_at);
+
+    display_evidence(ctx, e, burst.guild_id, evidence);
+
+    // once every report is resolved, it's colored like a resolved report
+    if open == 0 {
+        let status = match reports.iter().any(|r| r.status == ReportStatus::Accepted) {
+            true => ReportStatus::Accepted,
+            false => ReportStatus::Denied,
+        };
+
+        if let Some(c) = status.into_color() {
+            e.colour(c);
+        }
+    }
+
+    e
+}
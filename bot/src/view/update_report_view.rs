@@ -1,16 +1,33 @@
+use std::{collections::HashSet, time::Duration};
+
 use serenity::{
-    builder::CreateEmbed,
+    builder::{CreateComponents, CreateEmbed, CreateMessage},
     client::Context,
-    model::{channel::ReactionType, id::*, prelude::User},
+    http::{
+        request::{Request, RequestBuilder},
+        routing::RouteInfo,
+    },
+    json::{hashmap_to_json_map, JsonMap, Value},
+    model::{
+        application::component::ButtonStyle,
+        channel::{GuildChannel, Message, ReactionType},
+        id::*,
+        prelude::User,
+    },
     prelude::Mentionable,
+    utils::{content_safe, ContentSafeOptions},
 };
+use unicode_segmentation::UnicodeSegmentation;
 
 use crate::{
     database::{models::*, Database, MakeReportEffect},
+    services,
     state::State,
 };
 use thiserror::Error;
 
+use super::update_burst_view;
+
 #[derive(Debug, Error)]
 pub enum UpdateViewError {
     #[error("Report does not exist in database.")]
@@ -21,6 +38,8 @@ pub enum UpdateViewError {
     DiscordError(#[from] serenity::Error),
     #[error("This server has not been configured yet")]
     UnconfiguredServer,
+    #[error("Unable to check if the user is protected: {0}")]
+    ProtectedError(#[from] services::ProtectedError),
 }
 
 // TODO: simplify verbose error handling by propagating it up
@@ -29,9 +48,9 @@ pub async fn update_report_view(
     db: &Database,
     effect: MakeReportEffect,
 ) -> Result<(), UpdateViewError> {
-    let report_id = match effect {
-        MakeReportEffect::Created(id) => id,
-        MakeReportEffect::Updated(id) => id,
+    let (report_id, preview_archive_id) = match effect {
+        MakeReportEffect::Created(id, archive_id) => (id, archive_id),
+        MakeReportEffect::Updated(id) => (id, None),
         MakeReportEffect::Duplicate(_) => return Ok(()),
     };
 
@@ -40,7 +59,32 @@ pub async fn update_report_view(
         .await?
         .ok_or(UpdateViewError::ReportDoesntExist)?;
 
-    update_mod_view(&ctx, &db, &report).await?;
+    // reports held back until their message is flagged enough aren't shown
+    if db.load_pending_report(report.id).await? {
+        return Ok(());
+    }
+
+    let case = db.load_report_case(report.id).await?;
+
+    // reports grouped into a case are shown on the mod view of the report
+    // leading the case
+    let (lead, preview_archive_id) = match &case {
+        Some(case) if case.lead_report_id != report.id => {
+            let lead = db
+                .load_report(case.lead_report_id)
+                .await?
+                .ok_or(UpdateViewError::ReportDoesntExist)?;
+            (lead, None)
+        }
+        _ => (report.clone(), preview_archive_id),
+    };
+
+    // reports in a burst share a single mod view instead of having their own,
+    // and so does the case of a report in a burst
+    match db.load_report_burst(lead.id).await? {
+        Some(burst) => update_burst_view(ctx, db, &burst).await?,
+        None => update_mod_view(&ctx, &db, &lead, case.as_ref(), preview_archive_id).await?,
+    };
     update_user_view(&ctx, &db, &report).await?;
 
     Ok(())
@@ -59,16 +103,24 @@ async fn update_user_view(
         .await
         .unwrap_or_else(|e| e.to_string());
 
+    let actions = db.load_report_actions(report.id).await?;
+    let config = db.get_server_config(&report.guild_id).await?;
+    let category = (report.category_id)
+        .zip(config.as_ref())
+        .and_then(|(id, config)| config.category(id));
+
     let msg = match view {
         Some(view) => {
             dms.edit_message(&ctx, view.message_id, |m| {
-                m.embed(|e| display_user_view(&report, e, channel_name))
+                m.components(display_user_view_buttons)
+                    .embed(|e| display_user_view(&report, e, channel_name, &actions, category))
             })
             .await?
         }
         None => {
             dms.send_message(&ctx, |m| {
-                m.embed(|e| display_user_view(&report, e, channel_name))
+                m.components(display_user_view_buttons)
+                    .embed(|e| display_user_view(&report, e, channel_name, &actions, category))
             })
             .await?
         }
@@ -77,9 +129,6 @@ async fn update_user_view(
     msg.react(&ctx, ReactionType::Unicode("🔄".to_owned()))
         .await?;
 
-    msg.react(&ctx, ReactionType::Unicode("📝".to_owned()))
-        .await?;
-
     let updated_model = UserViewModel {
         report_id: report.id,
         message_id: msg.id,
@@ -91,26 +140,53 @@ async fn update_user_view(
     Ok(())
 }
 
+pub const EDIT_REASON_BUTTON: &str = "user_view_edit_reason";
+
+fn display_user_view_buttons(c: &mut CreateComponents) -> &mut CreateComponents {
+    c.create_action_row(|row| {
+        row.create_button(|b| {
+            b.custom_id(EDIT_REASON_BUTTON)
+                .label("Edit Reason")
+                .emoji(ReactionType::Unicode("📝".to_owned()))
+                .style(ButtonStyle::Primary)
+        })
+    })
+}
+
 fn display_user_view<'a>(
     report: &ReportModel,
     e: &'a mut CreateEmbed,
     channel_name: String,
+    actions: &[ReportActionModel],
+    category: Option<&ReportCategoryModel>,
 ) -> &'a mut CreateEmbed {
     e.title(format!("Report (ID #{})", report.id));
 
     e.field("Reported User", report.reported_user_id.mention(), true)
         .field("Status", report.status.into_human_status(), true);
 
+    display_category(e, category);
+
     if let Some(url) = report.url() {
         e.field("Location", format!("[#{}]({})", channel_name, url), true);
     }
 
+    if !actions.is_empty() {
+        let actions = (actions.iter())
+            .map(|a| display_action(a.action))
+            .collect::<Vec<_>>();
+
+        e.field("Action Taken", actions.join("\n"), true);
+    }
+
+    display_timestamps(report, e);
+
     e.field(
         "Provided Reason",
         report
             .reason
             .as_deref()
-            .unwrap_or("No reason provided! React with 📝 to provide one."),
+            .unwrap_or("No reason provided! Press 📝 Edit Reason to provide one."),
         false,
     );
 
@@ -125,13 +201,20 @@ async fn update_mod_view(
     ctx: &Context,
     db: &Database,
     report: &ReportModel,
+    case: Option<&ReportCaseModel>,
+    preview_archive_id: Option<u64>,
 ) -> Result<(), UpdateViewError> {
     let view = db.load_mod_view(report.id).await?;
     let maybe_config = db.get_server_config(&report.guild_id).await?;
     let config = maybe_config.ok_or(UpdateViewError::UnconfiguredServer)?;
 
     // TODO: handle a changed reports channel and whatnot?
-    let channel_id = ChannelId(config.reports_channel);
+    let category = report.category_id.and_then(|id| config.category(id));
+    let mut channel_id = match &view {
+        Some(view) => view.channel_id,
+        None => config.reports_channel_for(report.category_id),
+    };
+    let mut thread_id = view.as_ref().and_then(|v| v.thread_id);
     let channel_name = report
         .channel_name(&ctx)
         .await
@@ -140,40 +223,216 @@ async fn update_mod_view(
     let reporter = report.accuser_user_id.to_user(&ctx).await?;
     let reported = report.reported_user_id.to_user(&ctx).await?;
 
+    // the preview stays as the message was when it was reported, unless we're
+    // explicitly given a newer archive to preview
+    let preview_archive_id = preview_archive_id
+        .or_else(|| view.as_ref().map(|v| v.preview_archive_id))
+        .unwrap_or(0);
+
+    let mut preview = match preview_archive_id {
+        0 => None,
+        id => db
+            .load_archived_message_preview(id)
+            .await?
+            .map(|p| display_preview(ctx, &p)),
+    };
+
+    if let Some(message_id) = report.message_id {
+        let history = db.load_message_history(&message_id).await?;
+        let prefix =
+            (db.get_server_prefix(&report.guild_id).await?).unwrap_or_else(|| "n!".to_owned());
+
+        if let Some(history) = display_history(report, &history, &prefix) {
+            preview = Some(match preview {
+                Some(preview) => format!("{}\n{}", preview, history),
+                None => history,
+            });
+        }
+    }
+
+    let grouped = match case {
+        Some(case) => db.load_case_reports(case.id).await?,
+        None => Vec::new(),
+    };
+    let notes = db.load_report_notes(report.id).await?;
+    // evidence for any report in the case is shown on the lead's view
+    let evidence = match grouped.is_empty() {
+        true => db.load_report_evidence(report.id).await?,
+        false => load_evidence(db, &grouped).await?,
+    };
+    let actions = db.load_report_actions(report.id).await?;
+    let warning = match config.warn_reported_users {
+        true => db.load_report_warning(report.id).await?,
+        false => None,
+    };
+    let available_actions = available_actions(&config, report);
+    let summary = db
+        .load_reported_user_summary(&report.guild_id, &report.reported_user_id)
+        .await?;
+    let protected =
+        services::is_protected(ctx, db, report.guild_id, report.reported_user_id).await?;
+    let reputation = db
+        .load_reporter_reputation(&report.guild_id, &report.accuser_user_id)
+        .await?;
+
+    let handler = view.as_ref().and_then(|v| v.handler);
+    let use_buttons = !config.mod_view_reactions;
+    let is_open = report.status.is_open();
+    let thread_name = truncate(
+        &format!("#{} {}", report.id, reported.name),
+        THREAD_NAME_MAX_LENGTH,
+    );
+
     // TODO: handle if the report message got deleted
     let msg = match &view {
         Some(mod_view) => {
+            // messages in archived threads can't be edited
+            if let Some(thread_id) = thread_id.filter(|_| mod_view.is_forum_post()) {
+                thread_id.edit_thread(ctx, |t| t.archived(false)).await?;
+            }
+
             let view = Some(mod_view);
             channel_id
                 .edit_message(&ctx, mod_view.message_id, |m| {
+                    // an empty set of components clears the buttons if the
+                    // server switched to reactions
+                    m.components(|c| match use_buttons {
+                        true => display_mod_view_buttons(
+                            c,
+                            report,
+                            handler,
+                            &available_actions,
+                            &config,
+                            warning.as_ref(),
+                        ),
+                        false => c,
+                    });
+
                     m.embed(|e| {
-                        display_mod_view(&report, view, e, channel_name, reported, reporter)
+                        let e = display_mod_view(
+                            &report,
+                            view,
+                            e,
+                            channel_name,
+                            reported,
+                            reporter,
+                            preview,
+                        );
+                        let e = display_category(e, category);
+                        let e = display_reputation(e, &config, &reputation);
+                        let e = display_case(e, case, &grouped);
+                        let e = display_evidence(ctx, e, report.guild_id, &evidence);
+                        let e = display_actions(e, &actions);
+                        let e = display_warning(e, warning.as_ref());
+                        let e = display_summary(e, report, &summary, protected);
+                        let e = display_notes(ctx, e, &notes);
+                        fit_embed(e)
                     })
                 })
                 .await?
         }
         None => {
-            channel_id
-                .send_message(&ctx, |m| {
-                    m.embed(|e| {
-                        display_mod_view(&report, None, e, channel_name, reported, reporter)
-                    })
-                })
-                .await?
+            let mut create = CreateMessage::default();
+
+            {
+                let m = &mut create;
+                let mut roles = Vec::new();
+                roles.extend(config.mod_role_id);
+                roles.extend(category.and_then(|c| c.ping_role_id));
+                roles.dedup();
+
+                if !roles.is_empty() {
+                    let mentions = (roles.iter())
+                        .map(|r| r.mention().to_string())
+                        .collect::<Vec<_>>();
+
+                    m.content(mentions.join(" "))
+                        .allowed_mentions(|a| a.roles(roles));
+                }
+
+                if use_buttons {
+                    m.components(|c| {
+                        display_mod_view_buttons(
+                            c,
+                            report,
+                            handler,
+                            &available_actions,
+                            &config,
+                            warning.as_ref(),
+                        )
+                    });
+                }
+
+                m.embed(|e| {
+                    let e = display_mod_view(
+                        &report,
+                        None,
+                        e,
+                        channel_name,
+                        reported,
+                        reporter,
+                        preview,
+                    );
+                    let e = display_category(e, category);
+                    let e = display_reputation(e, &config, &reputation);
+                    let e = display_case(e, case, &grouped);
+                    let e = display_evidence(ctx, e, report.guild_id, &evidence);
+                    let e = display_actions(e, &actions);
+                    let e = display_warning(e, warning.as_ref());
+                    let e = display_summary(e, report, &summary, protected);
+                    let e = display_notes(ctx, e, &notes);
+                    fit_embed(e)
+                });
+            }
+
+            match is_forum(ctx, channel_id).await? {
+                true => {
+                    let (post_id, msg) =
+                        create_forum_post(ctx, channel_id, &thread_name, create).await?;
+
+                    // the mod view is the starter message of the post
+                    channel_id = post_id;
+                    thread_id = Some(post_id);
+                    msg
+                }
+                false => {
+                    channel_id
+                        .send_message(&ctx, |m| {
+                            *m = create;
+                            m
+                        })
+                        .await?
+                }
+            }
         }
     };
 
-    msg.react(&ctx, ReactionType::Unicode("🔄".to_owned()))
-        .await?;
+    // reports that were open before threads were turned on get one too
+    if config.mod_view_threads && thread_id.is_none() && is_open {
+        let thread = channel_id
+            .create_public_thread(ctx, msg.id, |t| t.name(&thread_name))
+            .await?;
+        thread_id = Some(thread.id);
+    }
 
-    msg.react(&ctx, ReactionType::Unicode("🛄".to_owned()))
-        .await?;
+    if use_buttons && !msg.reactions.is_empty() {
+        // left over from when the server used reactions
+        msg.delete_reactions(ctx).await?;
+    }
 
-    msg.react(&ctx, ReactionType::Unicode("❌".to_owned()))
-        .await?;
+    if !use_buttons {
+        msg.react(&ctx, ReactionType::Unicode("🔄".to_owned()))
+            .await?;
 
-    msg.react(&ctx, ReactionType::Unicode("✅".to_owned()))
-        .await?;
+        msg.react(&ctx, ReactionType::Unicode("🛄".to_owned()))
+            .await?;
+
+        msg.react(&ctx, ReactionType::Unicode("❌".to_owned()))
+            .await?;
+
+        msg.react(&ctx, ReactionType::Unicode("✅".to_owned()))
+            .await?;
+    }
 
     match report.status {
         ReportStatus::Unhandled | ReportStatus::Reviewing => {
@@ -190,17 +449,248 @@ async fn update_mod_view(
         report_id: report.id,
         channel_id,
         message_id: msg.id,
-        // TODO: handle valid message id in archive
-        preview_archive_id: 0,
-        // TODO: handle mods clicking buttons n stuff
-        handler: view.and_then(|v| v.handler),
+        preview_archive_id,
+        handler,
+        thread_id,
     };
 
     db.save_mod_view(updated_model).await?;
 
+    // the discussion is over once the report is resolved
+    if let Some(thread_id) = thread_id.filter(|_| !is_open) {
+        thread_id.edit_thread(ctx, |t| t.archived(true)).await?;
+    }
+
     Ok(())
 }
 
+/// The longest name Discord allows a thread to have.
+pub(super) const THREAD_NAME_MAX_LENGTH: usize = 100;
+
+/// The raw type of forum channels. Serenity only knows about forums with the
+/// unstable Discord API, and reads them as an unknown channel type otherwise.
+const FORUM_CHANNEL_TYPE: u64 = 15;
+
+pub(super) async fn is_forum(ctx: &Context, channel_id: ChannelId) -> serenity::Result<bool> {
+    let route = RouteInfo::GetChannel {
+        channel_id: channel_id.0,
+    };
+
+    let channel: Value = ctx
+        .http
+        .fire(Request::new(RequestBuilder::new(route)))
+        .await?;
+
+    Ok(channel.get("type").and_then(Value::as_u64) == Some(FORUM_CHANNEL_TYPE))
+}
+
+/// Posts a message in a forum channel, returning the ID of the post and its
+/// starter message.
+pub(super) async fn create_forum_post(
+    ctx: &Context,
+    forum_id: ChannelId,
+    name: &str,
+    message: CreateMessage<'_>,
+) -> serenity::Result<(ChannelId, Message)> {
+    let mut map = JsonMap::new();
+    map.insert("name".to_owned(), Value::from(name));
+    map.insert(
+        "message".to_owned(),
+        Value::Object(hashmap_to_json_map(message.0)),
+    );
+    let body = Value::Object(map).to_string();
+
+    // serenity can't make forum posts yet. discord makes them through
+    // `POST /channels/{id}/threads` with the starter message in the body, a
+    // route serenity only knows by its use for private threads
+    let route = RouteInfo::CreatePrivateThread {
+        channel_id: forum_id.0,
+    };
+    let mut request = RequestBuilder::new(route);
+    request.body(Some(body.as_bytes()));
+
+    let post: GuildChannel = ctx.http.fire(Request::new(request)).await?;
+
+    // the starter message of a post shares its ID
+    let msg = post.id.message(ctx, MessageId(post.id.0)).await?;
+
+    Ok((post.id, msg))
+}
+
+pub const REFRESH_BUTTON: &str = "mod_view_refresh";
+pub const CLAIM_BUTTON: &str = "mod_view_claim";
+pub const UNCLAIM_BUTTON: &str = "mod_view_unclaim";
+pub const ACCEPT_BUTTON: &str = "mod_view_accept";
+pub const DENY_BUTTON: &str = "mod_view_deny";
+pub const NOTE_BUTTON: &str = "mod_view_note";
+pub const WARNING_BUTTON: &str = "mod_view_warning";
+pub const ACTION_SELECT: &str = "mod_view_action";
+
+/// The durations moderators can choose from when timing out a member.
+const TIMEOUT_DURATIONS: [Duration; 4] = [
+    Duration::from_secs(10 * 60),
+    Duration::from_secs(60 * 60),
+    Duration::from_secs(24 * 60 * 60),
+    Duration::from_secs(7 * 24 * 60 * 60),
+];
+
+/// Lists the moderation actions the server allows to be taken on a report.
+fn available_actions(config: &ServerConfiguration, report: &ReportModel) -> Vec<ModerationAction> {
+    let mut actions = Vec::new();
+
+    for kind in ModerationActionKind::ALL {
+        if !config.allows_action(kind) {
+            continue;
+        }
+
+        match kind {
+            ModerationActionKind::DeleteMessage if report.message_id.is_some() => {
+                actions.push(ModerationAction::DeleteMessage)
+            }
+            ModerationActionKind::DeleteMessage => {}
+            ModerationActionKind::Timeout => actions.extend(
+                TIMEOUT_DURATIONS
+                    .iter()
+                    .map(|d| ModerationAction::Timeout(*d)),
+            ),
+            ModerationActionKind::Kick => actions.push(ModerationAction::Kick),
+            ModerationActionKind::Ban => actions.push(ModerationAction::Ban),
+        };
+    }
+
+    actions
+}
+
+/// Encodes a moderation action as the value of an option in the action menu.
+fn action_value(action: ModerationAction) -> String {
+    match action {
+        ModerationAction::Timeout(duration) => format!("timeout:{}", duration.as_secs()),
+        action => action.kind().name().to_owned(),
+    }
+}
+
+/// Decodes the value of an option in the action menu.
+pub fn parse_action_value(value: &str) -> Option<ModerationAction> {
+    match value.split_once(':') {
+        Some(("timeout", seconds)) => Some(ModerationAction::Timeout(Duration::from_secs(
+            seconds.parse().ok()?,
+        ))),
+        Some(_) => None,
+        None => match value {
+            "delete" => Some(ModerationAction::DeleteMessage),
+            "kick" => Some(ModerationAction::Kick),
+            "ban" => Some(ModerationAction::Ban),
+            _ => None,
+        },
+    }
+}
+
+pub(crate) fn display_action(action: ModerationAction) -> String {
+    match action {
+        ModerationAction::DeleteMessage => "🗑️ Deleted the message".to_owned(),
+        ModerationAction::Timeout(duration) => {
+            format!("🔇 Timed out for {}", display_duration(duration))
+        }
+        ModerationAction::Kick => "👢 Kicked".to_owned(),
+        ModerationAction::Ban => "🔨 Banned".to_owned(),
+    }
+}
+
+fn display_mod_view_buttons<'a>(
+    c: &'a mut CreateComponents,
+    report: &ReportModel,
+    handler: Option<UserId>,
+    available_actions: &[ModerationAction],
+    config: &ServerConfiguration,
+    warning: Option<&ReportWarningModel>,
+) -> &'a mut CreateComponents {
+    let is_open = matches!(
+        report.status,
+        ReportStatus::Unhandled | ReportStatus::Reviewing
+    );
+    let is_claimed = report.status == ReportStatus::Reviewing && handler.is_some();
+
+    c.create_action_row(|row| {
+        row.create_button(|b| {
+            b.custom_id(REFRESH_BUTTON)
+                .label("Refresh")
+                .emoji(ReactionType::Unicode("🔄".to_owned()))
+                .style(ButtonStyle::Secondary)
+        })
+        .create_button(|b| {
+            b.custom_id(CLAIM_BUTTON)
+                .label("Claim")
+                .emoji(ReactionType::Unicode("🛄".to_owned()))
+                .style(ButtonStyle::Primary)
+                .disabled(!is_open || is_claimed)
+        })
+        .create_button(|b| {
+            b.custom_id(UNCLAIM_BUTTON)
+                .label("Unclaim")
+                .style(ButtonStyle::Secondary)
+                .disabled(!is_claimed)
+        })
+        // resolved reports can't be accepted or denied again
+        .create_button(|b| {
+            b.custom_id(ACCEPT_BUTTON)
+                .label("Accept")
+                .emoji(ReactionType::Unicode("✅".to_owned()))
+                .style(ButtonStyle::Success)
+                .disabled(!is_open)
+        })
+        .create_button(|b| {
+            b.custom_id(DENY_BUTTON)
+                .label("Deny")
+                .emoji(ReactionType::Unicode("❌".to_owned()))
+                .style(ButtonStyle::Danger)
+                .disabled(!is_open)
+        })
+    })
+    .create_action_row(|row| {
+        row.create_button(|b| {
+            b.custom_id(NOTE_BUTTON)
+                .label("Add Note")
+                .emoji(ReactionType::Unicode("🗒️".to_owned()))
+                .style(ButtonStyle::Secondary)
+        });
+
+        // the warning can be written until it's been sent
+        if config.warn_reported_users {
+            row.create_button(|b| {
+                b.custom_id(WARNING_BUTTON)
+                    .label("Edit Warning")
+                    .emoji(ReactionType::Unicode("⚠️".to_owned()))
+                    .style(ButtonStyle::Secondary)
+                    .disabled(warning.and_then(|w| w.sent).is_some())
+            });
+        }
+
+        row
+    });
+
+    if !available_actions.is_empty() {
+        c.create_action_row(|row| {
+            row.create_select_menu(|menu| {
+                // actions can only be taken on reports that are still open
+                menu.custom_id(ACTION_SELECT)
+                    .placeholder("Accept and take action...")
+                    .disabled(!is_open)
+                    .options(|o| {
+                        for action in available_actions {
+                            o.create_option(|opt| {
+                                opt.label(display_action(*action))
+                                    .value(action_value(*action))
+                            });
+                        }
+                        o
+                    })
+            })
+        });
+    }
+
+    c
+}
+
 fn display_mod_view<'a>(
     report: &ReportModel,
     view: Option<&ModViewModel>,
@@ -208,6 +698,7 @@ fn display_mod_view<'a>(
     channel_name: String,
     reported: User,
     reporter: User,
+    preview: Option<String>,
 ) -> &'a mut CreateEmbed {
     let reporter_user = reporter;
     let reported_user = reported;
@@ -218,8 +709,6 @@ fn display_mod_view<'a>(
     let reported_user_mention = reported_user.mention();
     let reporter_user_mention = reporter_user.mention();
 
-    let preview = Option::<String>::None;
-
     e.author(|a| {
         a.icon_url(avatar_url)
             .name(format!("Report (ID #{})", report.id))
@@ -240,6 +729,8 @@ fn display_mod_view<'a>(
         e.field("Report Handler", format!("{}", handler.mention()), true);
     }
 
+    display_timestamps(report, e);
+
     if let Some(reason) = &report.reason {
         e.field("Provided Reason", reason, false);
     }
@@ -254,3 +745,478 @@ fn display_mod_view<'a>(
 
     e
 }
+
+fn display_category<'a>(
+    e: &'a mut CreateEmbed,
+    category: Option<&ReportCategoryModel>,
+) -> &'a mut CreateEmbed {
+    let category = match category {
+        Some(category) => category,
+        None => return e,
+    };
+
+    let name = match category.display_emoji() {
+        Some(emoji) => format!("{} {}", emoji, category.name),
+        None => category.name.clone(),
+    };
+
+    e.field("Category", name, true)
+}
+
+fn display_reputation<'a>(
+    e: &'a mut CreateEmbed,
+    config: &ServerConfiguration,
+    reputation: &ReporterReputationModel,
+) -> &'a mut CreateEmbed {
+    let mut text = match reputation.score() {
+        Some(score) => format!(
+            "{}% accepted ({} of {} resolved reports)",
+            score,
+            reputation.accepted,
+            reputation.resolved()
+        ),
+        None => format!(
+            "Not enough resolved reports yet ({} of {})",
+            reputation.resolved(),
+            MIN_RESOLVED_REPORTS
+        ),
+    };
+
+    if let (Some(threshold), true) = (
+        config.reputation_threshold,
+        config.is_below_reputation_threshold(reputation),
+    ) {
+        text.push_str(&format!(
+            "\n⚠️ Below this server's threshold of {}%",
+            threshold
+        ));
+    }
+
+    e.field("Reporter Reputation", text, false)
+}
+
+/// The most characters the list of reports in a case may take up.
+const CASE_LENGTH: usize = 1000;
+
+fn display_case<'a>(
+    e: &'a mut CreateEmbed,
+    case: Option<&ReportCaseModel>,
+    reports: &[ReportModel],
+) -> &'a mut CreateEmbed {
+    let case = match case {
+        Some(case) => case,
+        None => return e,
+    };
+
+    let reporters = (reports.iter())
+        .map(|r| r.accuser_user_id)
+        .collect::<HashSet<_>>();
+
+    let target = match case.message_id {
+        Some(_) => "this message",
+        None => "this user",
+    };
+
+    let mut text = format!(
+        "**{}** user(s) reported {} in **{}** report(s). Accepting or denying this report resolves all of them.",
+        reporters.len(),
+        target,
+        reports.len()
+    );
+    let mut length = text.chars().count();
+
+    for (idx, report) in reports.iter().enumerate() {
+        let line = format!(
+            "\n`#{}` {}: {}",
+            report.id,
+            report.accuser_user_id.mention(),
+            (report.reason.as_deref())
+                .map(|r| truncate(r, 100))
+                .unwrap_or_else(|| "*no reason*".to_owned())
+        );
+
+        length += line.chars().count();
+
+        if length > CASE_LENGTH {
+            text.push_str(&format!("\n*...and {} more*", reports.len() - idx));
+            break;
+        }
+
+        text.push_str(&line);
+    }
+
+    e.field(format!("Case (#{})", case.id), text, false)
+}
+
+const EVIDENCE_LENGTH: usize = 1000;
+
+/// Loads the evidence of several reports, in the order of the reports.
+pub(super) async fn load_evidence(
+    db: &Database,
+    reports: &[ReportModel],
+) -> Result<Vec<ReportEvidenceModel>, sqlx::Error> {
+    let mut evidence = Vec::new();
+
+    for report in reports {
+        evidence.extend(db.load_report_evidence(report.id).await?);
+    }
+
+    Ok(evidence)
+}
+
+pub(super) fn display_evidence<'a>(
+    ctx: &Context,
+    e: &'a mut CreateEmbed,
+    guild_id: GuildId,
+    evidence: &[ReportEvidenceModel],
+) -> &'a mut CreateEmbed {
+    if evidence.is_empty() {
+        return e;
+    }
+
+    // evidence from several reports is labelled with the report it's for
+    let several = evidence
+        .iter()
+        .any(|i| i.report_id != evidence[0].report_id);
+
+    let mut lines = Vec::new();
+    let mut length = 0;
+
+    for (idx, item) in evidence.iter().enumerate() {
+        let mut line = match several {
+            true => format!("`#{}.{}`", item.report_id, item.idx + 1),
+            false => format!("`{}.`", idx + 1),
+        };
+
+        // screenshots were sent to the bot, so there's no message to link to
+        if item.is_linked {
+            line.push_str(&format!(
+                " [Message]({}) in {}",
+                item.message_id.link(item.channel_id, Some(guild_id)),
+                item.channel_id.mention()
+            ));
+        }
+
+        if !item.content.trim().is_empty() {
+            let content = content_safe(ctx, &item.content, &ContentSafeOptions::default(), &[]);
+            line.push_str(&format!(" {}", truncate(&content, 80)));
+        }
+
+        for (idx, url) in item.attachment_urls.iter().enumerate() {
+            line.push_str(&format!(" [📎 {}]({})", idx + 1, url));
+        }
+
+        // account for the newline between items
+        length += line.chars().count() + 1;
+
+        if length > EVIDENCE_LENGTH {
+            lines.push(format!("*...and {} more*", evidence.len() - idx));
+            break;
+        }
+
+        lines.push(line);
+    }
+
+    e.field("Evidence", lines.join("\n"), false)
+}
+
+fn display_actions<'a>(
+    e: &'a mut CreateEmbed,
+    actions: &[ReportActionModel],
+) -> &'a mut CreateEmbed {
+    if actions.is_empty() {
+        return e;
+    }
+
+    let lines = (actions.iter())
+        .map(|a| {
+            format!(
+                "{} by {} (<t:{}:R>)",
+                display_action(a.action),
+                a.moderator.mention(),
+                a.created.timestamp()
+            )
+        })
+        .collect::<Vec<_>>();
+
+    e.field("Actions Taken", lines.join("\n"), false)
+}
+
+fn display_warning<'a>(
+    e: &'a mut CreateEmbed,
+    warning: Option<&ReportWarningModel>,
+) -> &'a mut CreateEmbed {
+    let warning = match warning {
+        Some(warning) => warning,
+        None => return e,
+    };
+
+    let mut lines = vec![
+        format!(
+            "**Rule:** {}",
+            warning.rule.as_deref().unwrap_or("*Not specified*")
+        ),
+        format!("**Note:** {}", warning.note.as_deref().unwrap_or("*None*")),
+    ];
+
+    if let Some(sent) = warning.sent {
+        lines.push(format!("*Sent <t:{}:R>*", sent.timestamp()));
+    }
+
+    e.field("Warning", lines.join("\n"), false)
+}
+
+fn display_summary<'a>(
+    e: &'a mut CreateEmbed,
+    report: &ReportModel,
+    summary: &ReportedUserSummaryModel,
+    protected: bool,
+) -> &'a mut CreateEmbed {
+    let mut text = format!(
+        "Reported {} time(s) by {} user(s)\n✅ {} accepted · ❌ {} denied · 🔎 {} open",
+        summary.total,
+        summary.reporters,
+        summary.accepted,
+        summary.denied,
+        summary.open()
+    );
+
+    if protected {
+        text.push_str("\n🛡️ This user is protected");
+    }
+
+    text.push_str(&format!("\n(see `n!profile {}`)", report.reported_user_id));
+
+    e.field("Accused User History", text, false)
+}
+
+/// Embed fields are limited to 1024 characters, so only the most recent notes
+/// that fit are shown. Some room is left to mention the notes that were hidden.
+const NOTES_LENGTH: usize = 1000;
+
+fn display_notes<'a>(
+    ctx: &Context,
+    e: &'a mut CreateEmbed,
+    notes: &[ReportNoteModel],
+) -> &'a mut CreateEmbed {
+    if notes.is_empty() {
+        return e;
+    }
+
+    let mut lines = Vec::new();
+    let mut length = 0;
+
+    for note in notes.iter().rev() {
+        let content = content_safe(ctx, &note.content, &ContentSafeOptions::default(), &[]);
+        let line = format!(
+            "{} (<t:{}:R>): {}",
+            note.author.mention(),
+            note.created.timestamp(),
+            content
+        );
+
+        // account for the newline between notes
+        length += line.chars().count() + 1;
+
+        if length > NOTES_LENGTH {
+            break;
+        }
+
+        lines.push(line);
+    }
+
+    if lines.len() < notes.len() {
+        lines.push(format!(
+            "*{} older note(s) hidden*",
+            notes.len() - lines.len()
+        ));
+    }
+
+    lines.reverse();
+
+    e.field("Moderator Notes", lines.join("\n"), false)
+}
+
+fn display_timestamps(report: &ReportModel, e: &mut CreateEmbed) {
+    if let Some(created_at) = report.created_at {
+        e.field(
+            "Reported",
+            format!("<t:{}:R>", created_at.timestamp()),
+            true,
+        );
+    }
+
+    match (report.resolved_at, report.response_time()) {
+        (Some(resolved_at), Some(response_time)) => e.field(
+            "Resolved",
+            format!(
+                "<t:{}:R> (after {})",
+                resolved_at.timestamp(),
+                display_duration(response_time)
+            ),
+            true,
+        ),
+        (Some(resolved_at), None) => e.field(
+            "Resolved",
+            format!("<t:{}:R>", resolved_at.timestamp()),
+            true,
+        ),
+        (None, _) => match report.updated_at.filter(|u| Some(*u) != report.created_at) {
+            Some(updated_at) => e.field(
+                "Last Updated",
+                format!("<t:{}:R>", updated_at.timestamp()),
+                true,
+            ),
+            None => e,
+        },
+    };
+}
+
+/// Formats a duration with its two most significant units, e.g. `2h 5m`.
+pub(crate) fn display_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+
+    let units = [
+        (seconds / 86400, "d"),
+        (seconds / 3600 % 24, "h"),
+        (seconds / 60 % 60, "m"),
+        (seconds % 60, "s"),
+    ];
+
+    let parts = (units.iter())
+        .skip_while(|(amount, _)| *amount == 0)
+        .take(2)
+        .filter(|(amount, _)| *amount > 0)
+        .map(|(amount, unit)| format!("{}{}", amount, unit))
+        .collect::<Vec<_>>();
+
+    match parts.is_empty() {
+        true => "0s".to_owned(),
+        false => parts.join(" "),
+    }
+}
+
+/// The maximum amount of characters from the reported message to show in the
+/// preview. This keeps the mod view from being dominated by long messages, and
+/// leaves room in the field (which is limited to 1024 characters) for notes
+/// about attachments and edits.
+const PREVIEW_LENGTH: usize = 768;
+
+fn display_preview(ctx: &Context, preview: &ArchivedMessagePreviewModel) -> String {
+    let content = content_safe(ctx, &preview.content, &ContentSafeOptions::default(), &[]);
+    let mut text = truncate(&content, PREVIEW_LENGTH);
+
+    if text.trim().is_empty() {
+        text = "*No text content*".to_owned();
+    }
+
+    match preview.attachment_count {
+        0 => {}
+        1 => text.push_str("\n📎 1 attachment"),
+        n => text.push_str(&format!("\n📎 {} attachments", n)),
+    };
+
+    text
+}
+
+fn display_history(
+    report: &ReportModel,
+    history: &MessageHistoryModel,
+    prefix: &str,
+) -> Option<String> {
+    let mut notes = Vec::new();
+
+    match history.edits() {
+        0 => {}
+        1 => notes.push("✏️ Edited once".to_owned()),
+        n => notes.push(format!("✏️ Edited {} times", n)),
+    };
+
+    if let Some(deleted) = history.deleted {
+        notes.push(format!("🗑️ Deleted <t:{}:R>", deleted.timestamp()));
+    }
+
+    if notes.is_empty() {
+        return None;
+    }
+
+    let mut text = notes.join(" · ");
+
+    if history.edits() > 0 {
+        text.push_str(&format!(" (see `{}revisions {}`)", prefix, report.id));
+    }
+
+    Some(text)
+}
+
+/// The most characters Discord allows in an embed, across its title,
+/// description, fields, footer and author.
+const EMBED_MAX_LENGTH: usize = 6000;
+
+/// Shortens the fields of an embed until it fits within Discord's limit,
+/// starting with the last field, since fields are shown in order of
+/// importance.
+fn fit_embed(e: &mut CreateEmbed) -> &mut CreateEmbed {
+    fn text_length(value: Option<&Value>) -> usize {
+        value
+            .and_then(Value::as_str)
+            .map_or(0, |s| s.chars().count())
+    }
+
+    let mut length = text_length(e.0.get("title"))
+        + text_length(e.0.get("description"))
+        + text_length(e.0.get("footer").and_then(|f| f.get("text")))
+        + text_length(e.0.get("author").and_then(|a| a.get("name")));
+
+    if let Some(fields) = e.0.get_mut("fields").and_then(Value::as_array_mut) {
+        for field in fields.iter() {
+            length += text_length(field.get("name")) + text_length(field.get("value"));
+        }
+
+        for field in fields.iter_mut().rev() {
+            if length <= EMBED_MAX_LENGTH {
+                break;
+            }
+
+            let value = match field.get("value").and_then(Value::as_str) {
+                Some(value) => value.to_owned(),
+                None => continue,
+            };
+            let value_length = value.chars().count();
+
+            // field values can't be empty, so at least the ellipsis is kept
+            let max_chars = value_length.saturating_sub(length - EMBED_MAX_LENGTH);
+            let shortened = truncate(&value, max_chars.max(3));
+
+            length = length - value_length + shortened.chars().count();
+            field["value"] = Value::from(shortened);
+        }
+    }
+
+    e
+}
+
+/// Truncates text to at most `max_chars` characters without splitting
+/// graphemes, marking that it was truncated with an ellipsis.
+pub(crate) fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() <= max_chars {
+        return text.to_owned();
+    }
+
+    let mut truncated = String::with_capacity(max_chars);
+    let mut chars = 0;
+
+    for grapheme in text.graphemes(true) {
+        chars += grapheme.chars().count();
+
+        // leave room for the ellipsis
+        if chars > max_chars.saturating_sub(3) {
+            break;
+        }
+
+        truncated.push_str(grapheme);
+    }
+
+    truncated.push_str("...");
+    truncated
+}